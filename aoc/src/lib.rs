@@ -0,0 +1,25 @@
+use tae_aoclib2025::Registry;
+
+/// All days of the workspace, in the order they are run by `aoc all`.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register(day_01::Day01);
+    registry.register(day_02::Day02);
+    registry.register(day_03::Day03);
+    registry.register(day_04::Day04);
+    registry.register(day_05::Day05);
+    registry.register(day_06::Day06);
+    registry.register(day_07::Day07);
+    registry.register(day_08::Day08);
+    registry.register(day_09::Day09);
+    registry.register(day_10::Day10);
+    registry.register(day_11::Day11);
+    registry.register(day_12::Day12);
+    registry.register(day_13::Day13);
+    registry.register(day_14::Day14);
+    registry.register(day_15::Day15);
+    registry.register(day_16::Day16);
+    registry.register(day_17::Day17);
+    registry.register(day_18::Day18);
+    registry
+}
@@ -0,0 +1,28 @@
+use aoc::registry;
+use tae_aoclib2025::{run_solvers, RunOptions, OPTIONS_USAGE};
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!("Usage: aoc [<day> | <first>..<last> | all] [<input>...] [options]");
+    eprintln!("{OPTIONS_USAGE}");
+    std::process::exit(2);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).peekable();
+    let selection = match args.next_if(|arg| !arg.starts_with('-')) {
+        Some(selection) => selection,
+        None => "all".to_string(),
+    };
+    let options = RunOptions::from_args(args).unwrap_or_else(|message| usage_error(&message));
+    // Solvers exceeding --timeout keep running on a detached thread, so they must live forever
+    let registry = Box::leak(Box::new(registry()));
+    let solvers = registry
+        .select(&selection)
+        .unwrap_or_else(|message| usage_error(&message));
+
+    let total = run_solvers(&solvers, &options);
+    if !total.success() {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use tae_aoclib2025::{expect_next, parse_token, Answer, ParseError, Rng, Solver};
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Parsed = (Vec<i64>, Vec<i64>);
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn name(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut left_numbers = Vec::new();
+        let mut right_numbers = Vec::new();
+        for line in input.lines() {
+            let mut split = line.split_whitespace();
+            let left = expect_next(input, line, &mut split, "a number")?;
+            let right = expect_next(input, line, &mut split, "a second number")?;
+            left_numbers.push(parse_token(input, left, "a number")?);
+            right_numbers.push(parse_token(input, right, "a number")?);
+        }
+        Ok((left_numbers, right_numbers))
+    }
+
+    fn part1(&self, (left_numbers, right_numbers): &Self::Parsed) -> Answer {
+        let mut left_numbers = left_numbers.clone();
+        let mut right_numbers = right_numbers.clone();
+        left_numbers.sort();
+        right_numbers.sort();
+        let mut result1 = 0;
+        for (left, right) in left_numbers.iter().zip(right_numbers.iter()) {
+            result1 += if left > right {
+                left - right
+            } else {
+                right - left
+            };
+        }
+        result1.into()
+    }
+
+    fn part2(&self, (left_numbers, right_numbers): &Self::Parsed) -> Answer {
+        let mut right_numbers_count: HashMap<i64, i64> = HashMap::new();
+        for right in right_numbers {
+            match right_numbers_count.get_mut(right) {
+                None => {
+                    right_numbers_count.insert(*right, 1);
+                }
+                Some(count) => *count += 1,
+            }
+        }
+        let mut result2 = 0;
+        for left in left_numbers {
+            if let Some(right_count) = right_numbers_count.get(left) {
+                result2 += left * right_count;
+            }
+        }
+        result2.into()
+    }
+
+    /// `size` lines of five-digit numbers. Half of the right numbers repeat a left number, so
+    /// that part 2 finds similarities.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let left: Vec<usize> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+        let mut input = String::new();
+        for left_number in &left {
+            let right_number = if rng.chance(0.5) {
+                *rng.choose(&left)
+            } else {
+                rng.range(10000..=99999)
+            };
+            input.push_str(&format!("{left_number}   {right_number}\n"));
+        }
+        input
+    }
+}
@@ -0,0 +1,18 @@
+use day_01::Day01;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day01 as Solver>::Parsed {
+    Day01.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day01.part1(&parsed()).to_string(), "11");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day01.part2(&parsed()).to_string(), "31");
+}
@@ -0,0 +1,114 @@
+use tae_aoclib2025::{parse_token, Answer, ParseError, Rng, Solver};
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Parsed = Vec<Vec<i64>>;
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn name(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let levels = line
+                    .split(" ")
+                    .map(|x| parse_token(input, x, "a number"))
+                    .collect::<Result<Vec<i64>, ParseError>>()?;
+                if levels.len() < 2 {
+                    return Err(ParseError::at(input, line, "at least two levels"));
+                }
+                Ok(levels)
+            })
+            .collect()
+    }
+
+    fn part1(&self, reports: &Self::Parsed) -> Answer {
+        let result1 = reports.iter().filter(|levels| line_is_safe(levels)).count();
+        result1.into()
+    }
+
+    fn part2(&self, reports: &Self::Parsed) -> Answer {
+        let result2 = reports
+            .iter()
+            .filter(|levels| has_at_most_one_unsafe(levels))
+            .count();
+        result2.into()
+    }
+
+    /// `size` reports of 5 to 8 levels, mostly safe with the occasional bad step.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let increasing = rng.chance(0.5);
+            let mut level = rng.range(50..=89) as i64;
+            let mut levels = Vec::new();
+            for _ in 0..rng.range(5..=8) {
+                levels.push(level.to_string());
+                let step = if rng.chance(0.1) {
+                    rng.range(0..=6)
+                } else {
+                    rng.range(1..=3)
+                } as i64;
+                level += if increasing { step } else { -step };
+            }
+            input.push_str(&levels.join(" "));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+pub fn line_is_safe(levels: &[i64]) -> bool {
+    // A single level, as left by removing one from a two-level report, is trivially safe
+    let [first, second, ..] = levels else {
+        return true;
+    };
+    let decreasing = first >= second;
+    for (level, next_level) in levels.iter().zip(levels.iter().skip(1)) {
+        if is_level_unsafe(level, next_level, decreasing) {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn is_level_unsafe(level: &i64, next_level: &i64, decreasing: bool) -> bool {
+    if level == next_level {
+        return true;
+    }
+    if decreasing {
+        if level < next_level {
+            return true;
+        }
+        if level - next_level > 3 {
+            return true;
+        }
+        false
+    } else {
+        if level > next_level {
+            return true;
+        }
+        if next_level - level > 3 {
+            return true;
+        }
+        false
+    }
+}
+
+pub fn has_at_most_one_unsafe(levels: &[i64]) -> bool {
+    for x in 0..levels.len() {
+        let mut levels_removed = levels.to_vec();
+        levels_removed.remove(x);
+        if line_is_safe(&levels_removed) {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,18 @@
+use day_02::Day02;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day02 as Solver>::Parsed {
+    Day02.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day02.part1(&parsed()).to_string(), "2");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day02.part2(&parsed()).to_string(), "4");
+}
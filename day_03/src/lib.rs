@@ -0,0 +1,177 @@
+use tae_aoclib2025::{log_trace, Answer, ParseError, Rng, Solver};
+
+pub struct Day03;
+
+pub enum Value {
+    None,
+    Expr(i64),
+    Do,
+    Dont,
+}
+
+impl Solver for Day03 {
+    type Parsed = Vec<Value>;
+
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut values = Vec::new();
+        let mut cursor = 0;
+        while cursor < input.len() {
+            let (advanced_cursor, result) = try_parse(cursor, input);
+            match result {
+                Value::None => {
+                    cursor += 1;
+                }
+                value => {
+                    cursor = advanced_cursor;
+                    values.push(value);
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    fn part1(&self, values: &Self::Parsed) -> Answer {
+        let mut result1 = 0;
+        for value in values {
+            if let Value::Expr(num) = value {
+                result1 += num;
+            }
+        }
+        result1.into()
+    }
+
+    fn part2(&self, values: &Self::Parsed) -> Answer {
+        let mut result2 = 0;
+        let mut enabled = true;
+        for value in values {
+            match value {
+                Value::None => {}
+                Value::Expr(num) => {
+                    if enabled {
+                        result2 += num;
+                    }
+                }
+                Value::Do => {
+                    enabled = true;
+                }
+                Value::Dont => {
+                    enabled = false;
+                }
+            }
+        }
+        result2.into()
+    }
+
+    /// `size` instructions in corrupted memory, with almost-instructions like `mul(4*` in
+    /// between and 50 instructions per line.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        const JUNK: [&str; 10] = [
+            "mul(4*",
+            "mul(6,9!",
+            "?(12,34)",
+            "mul ( 2 , 4 )",
+            "don't",
+            "do(",
+            "from()",
+            "mul[3,7]",
+            "%&",
+            "select()",
+        ];
+        let mut input = String::new();
+        for i in 0..size {
+            match rng.below(10) {
+                0 => input.push_str("do()"),
+                1 => input.push_str("don't()"),
+                _ => input.push_str(&format!(
+                    "mul({},{})",
+                    rng.range(1..=999),
+                    rng.range(1..=999)
+                )),
+            }
+            for _ in 0..rng.below(3) {
+                input.push_str(JUNK[rng.below(JUNK.len())]);
+            }
+            if i % 50 == 49 {
+                input.push('\n');
+            }
+        }
+        input.push('\n');
+        input
+    }
+}
+
+fn print(text: &str, cursor: usize, start_cursor: usize, extra: &str) {
+    log_trace!(
+        "{0}\n{1:>2$}{3:->4$} {5}",
+        text,
+        ":",
+        start_cursor,
+        ":",
+        cursor - start_cursor,
+        extra
+    );
+}
+
+/// Parses the instruction starting at byte `cursor`, which need not be at a char boundary.
+pub fn try_parse(mut cursor: usize, text: &str) -> (usize, Value) {
+    // Within a multi-byte char, which cannot start an instruction
+    let rest = |cursor: usize| text.get(cursor..).unwrap_or("");
+    if rest(cursor).starts_with("don't()") {
+        print(text, cursor + 7, cursor, "Success don't()");
+        return (cursor + 7, Value::Dont);
+    }
+    if rest(cursor).starts_with("do()") {
+        print(text, cursor + 4, cursor, "Success do()");
+        return (cursor + 4, Value::Do);
+    }
+
+    let start_cursor = cursor;
+    if !rest(cursor).starts_with("mul(") {
+        print(text, cursor, start_cursor, "Err mul(");
+        return (start_cursor, Value::None);
+    }
+    cursor += 4;
+    let (mut cursor, num1) = parse_number_up_to_3(cursor, text);
+    if num1.is_none() {
+        print(text, cursor, start_cursor, "Err num1");
+        return (start_cursor, Value::None);
+    }
+    if !rest(cursor).starts_with(",") {
+        print(text, cursor, start_cursor, "Err ,");
+        return (start_cursor, Value::None);
+    }
+    cursor += 1;
+    let (mut cursor, num2) = parse_number_up_to_3(cursor, text);
+    if num2.is_none() {
+        print(text, cursor, start_cursor, "Err num2");
+        return (start_cursor, Value::None);
+    }
+    if !rest(cursor).starts_with(")") {
+        print(text, cursor, start_cursor, "Err )");
+        return (start_cursor, Value::None);
+    }
+    cursor += 1;
+    print(text, cursor, start_cursor, "Success");
+    (cursor, Value::Expr(num1.unwrap() * num2.unwrap()))
+}
+
+pub fn parse_number_up_to_3(cursor: usize, text: &str) -> (usize, Option<i64>) {
+    for len in (1..=3).rev() {
+        // `None` near the end of the input or within a multi-byte char
+        let number = text
+            .get(cursor..cursor + len)
+            .and_then(|x| x.parse::<i64>().ok());
+        if number.is_some() {
+            return (cursor + len, number);
+        }
+    }
+    (cursor + 3, None)
+}
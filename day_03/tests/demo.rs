@@ -0,0 +1,30 @@
+use day_03::Day03;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day03 as Solver>::Parsed {
+    Day03.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day03.part1(&parsed()).to_string(), "161");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day03.part2(&parsed()).to_string(), "161");
+}
+
+#[test]
+fn truncated_instruction_at_end() {
+    let parsed = Day03.parse("mul(2,3)mul(1").unwrap();
+    assert_eq!(Day03.part1(&parsed).to_string(), "6");
+}
+
+#[test]
+fn multi_byte_chars_between_instructions() {
+    let parsed = Day03.parse("mul(2,3) é mul(4,5)").unwrap();
+    assert_eq!(Day03.part1(&parsed).to_string(), "26");
+}
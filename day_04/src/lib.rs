@@ -0,0 +1,195 @@
+use std::iter::{RepeatN, Rev, Zip};
+use std::ops::Range;
+use tae_aoclib2025::{log_debug, log_enabled, Answer, Level, ParseError, Rng, Solver};
+
+pub const SEARCH_WORD: &str = "XMAS";
+pub const SEARCH_WORD2: &str = "MAS";
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Parsed = Vec<String>;
+
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn name(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        // The search assumes a square grid of at least the search word's size
+        for line in &lines {
+            if line.len() != lines.len() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("a line of {} letters", lines.len()),
+                ));
+            }
+        }
+        if lines.len() < SEARCH_WORD.len() {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                format!("at least {} lines", SEARCH_WORD.len()),
+            ));
+        }
+        Ok(lines.iter().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(&self, lines: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let dim = lines.len();
+        let mut result1 = 0;
+        let target_len1 = SEARCH_WORD.len();
+        let targets_iter1 = SEARCH_WORD.chars().zip(SEARCH_WORD.chars().rev());
+        for x in 0..dim - (SEARCH_WORD.len() - 1) {
+            for y in 0..dim - (SEARCH_WORD.len() - 1) {
+                // Diagonal
+                // Direction /
+                if matches_search_word(
+                    lines,
+                    diagonal_ranges1(x, y, target_len1),
+                    targets_iter1.clone(),
+                ) {
+                    if debug_print {
+                        log_debug!("Found diag / at {},{}", x, y);
+                    }
+                    result1 += 1;
+                }
+                // Direction \
+                if matches_search_word(
+                    lines,
+                    diagonal_ranges2(x, y, target_len1),
+                    targets_iter1.clone(),
+                ) {
+                    if debug_print {
+                        log_debug!("Found diag \\ at {},{}", x, y);
+                    }
+                    result1 += 1;
+                }
+            }
+        }
+        // Horizontal
+        for x in 0..dim {
+            for y in 0..dim - (SEARCH_WORD.len() - 1) {
+                if matches_search_word(
+                    lines,
+                    horizontal_ranges(x, y, target_len1),
+                    targets_iter1.clone(),
+                ) {
+                    if debug_print {
+                        log_debug!("Found horizontal at {},{}", x, y);
+                    }
+                    result1 += 1;
+                }
+            }
+        }
+        // Vertical
+        for x in 0..dim - (SEARCH_WORD.len() - 1) {
+            for y in 0..dim {
+                if matches_search_word(
+                    lines,
+                    vertical_ranges(x, y, target_len1),
+                    targets_iter1.clone(),
+                ) {
+                    if debug_print {
+                        log_debug!("Found vertical at {},{}", x, y);
+                    }
+                    result1 += 1;
+                }
+            }
+        }
+        result1.into()
+    }
+
+    fn part2(&self, lines: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let dim = lines.len();
+        let mut result2 = 0;
+        let targets_iter2 = SEARCH_WORD2.chars().zip(SEARCH_WORD2.chars().rev());
+        let target_len2 = SEARCH_WORD2.len();
+        for x in 0..dim - (SEARCH_WORD2.len() - 1) {
+            for y in 0..dim - (SEARCH_WORD2.len() - 1) {
+                if
+                // Direction /
+                matches_search_word(
+                    lines,
+                    diagonal_ranges1(x, y, target_len2),
+                    targets_iter2.clone(),
+                ) && matches_search_word(
+                    lines,
+                    diagonal_ranges2(x, y, target_len2),
+                    targets_iter2.clone(),
+                ) {
+                    if debug_print {
+                        log_debug!("Found cross at {},{}", x, y);
+                    }
+                    result2 += 1;
+                }
+            }
+        }
+        result2.into()
+    }
+
+    /// A `size`x`size` grid of the letters of the search word, at least as big as the word.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let size = size.max(SEARCH_WORD.len());
+        let letters: Vec<char> = SEARCH_WORD.chars().collect();
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..size {
+                input.push(*rng.choose(&letters));
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+pub fn horizontal_ranges(
+    x: usize,
+    y: usize,
+    target_len: usize,
+) -> Zip<RepeatN<usize>, Range<usize>> {
+    std::iter::repeat_n(x, target_len).zip(y..(y + target_len))
+}
+
+pub fn vertical_ranges(x: usize, y: usize, target_len: usize) -> Zip<Range<usize>, RepeatN<usize>> {
+    (x..(x + target_len)).zip(std::iter::repeat_n(y, target_len))
+}
+
+// Direction \
+pub fn diagonal_ranges1(x: usize, y: usize, target_len: usize) -> Zip<Range<usize>, Range<usize>> {
+    (x..(x + target_len)).zip(y..(y + target_len))
+}
+
+// Direction /
+pub fn diagonal_ranges2(
+    x: usize,
+    y: usize,
+    target_len: usize,
+) -> Zip<Range<usize>, Rev<Range<usize>>> {
+    (x..(x + target_len)).zip((y..(y + target_len)).rev())
+}
+
+pub fn matches_search_word<I, T>(lines: &[String], index_ranges: I, targets_iter: T) -> bool
+where
+    I: Iterator<Item = (usize, usize)>,
+    T: Iterator<Item = (char, char)>,
+{
+    let mut found_backwards: bool = true;
+    let mut found_forwards: bool = true;
+    for ((x, y), (char_forwards, char_backwards)) in index_ranges.zip(targets_iter) {
+        if lines[x].chars().nth(y).unwrap() != char_forwards {
+            found_forwards = false;
+        }
+        if lines[x].chars().nth(y).unwrap() != char_backwards {
+            found_backwards = false;
+        }
+    }
+    found_backwards || found_forwards
+}
@@ -0,0 +1,18 @@
+use day_04::Day04;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day04 as Solver>::Parsed {
+    Day04.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day04.part1(&parsed()).to_string(), "18");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day04.part2(&parsed()).to_string(), "9");
+}
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+use tae_aoclib2025::{
+    expect_split_once, log_debug, log_enabled, parse_token, Answer, Level, ParseError, Rng, Solver,
+};
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Parsed = (Vec<(u64, u64)>, Vec<Vec<u64>>);
+
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn name(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        get_rules_and_updates(input)
+    }
+
+    fn part1(&self, (rules, updates): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let mut result1 = 0;
+        for update in updates {
+            let current_rules = rules_for_update(rules, update);
+            if satisfies_rules(update, &current_rules, debug_print) {
+                result1 += update[update.len() / 2];
+            }
+        }
+        result1.into()
+    }
+
+    fn part2(&self, (rules, updates): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let mut result2 = 0;
+        for update in updates {
+            let current_rules = rules_for_update(rules, update);
+            if !satisfies_rules(update, &current_rules, debug_print) {
+                let correct_order = order_correctly(update.clone(), current_rules, debug_print);
+                result2 += correct_order[correct_order.len() / 2];
+            }
+        }
+        result2.into()
+    }
+
+    /// Rules ordering 49 pages and `size` updates of 5 to 23 of them, half in the right order.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let mut pages: Vec<u64> = (10..100).collect();
+        rng.shuffle(&mut pages);
+        pages.truncate(49);
+        let mut rules = Vec::new();
+        for (i, before) in pages.iter().enumerate() {
+            for after in &pages[i + 1..] {
+                rules.push(format!("{before}|{after}\n"));
+            }
+        }
+        rng.shuffle(&mut rules);
+        let mut input = rules.concat();
+        input.push('\n');
+        for _ in 0..size {
+            let mut indices: Vec<usize> = (0..pages.len()).collect();
+            rng.shuffle(&mut indices);
+            indices.truncate(rng.range(2..=11) * 2 + 1);
+            if rng.chance(0.5) {
+                indices.sort();
+            }
+            let update: Vec<String> = indices.iter().map(|&i| pages[i].to_string()).collect();
+            input.push_str(&update.join(","));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+pub fn rules_for_update(rules: &[(u64, u64)], update: &[u64]) -> Vec<(u64, u64)> {
+    rules
+        .iter()
+        .filter(|(x, y)| update.contains(x) && update.contains(y))
+        .map(|(x, y)| (*x, *y))
+        .collect::<Vec<(u64, u64)>>()
+}
+
+pub type RulesAndUpdates = (Vec<(u64, u64)>, Vec<Vec<u64>>);
+
+pub fn get_rules_and_updates(input: &str) -> Result<RulesAndUpdates, ParseError> {
+    let mut rules = Vec::new();
+    let mut updates = Vec::new();
+    let mut update_lines = Vec::new();
+
+    let mut rules_section = true;
+    for line in input.lines() {
+        if line.is_empty() {
+            rules_section = false;
+            continue;
+        }
+        if rules_section {
+            let (x, y) = expect_split_once(input, line, "|")?;
+            rules.push((page_number(input, x)?, page_number(input, y)?));
+        } else {
+            let ls = line
+                .split(",")
+                .map(|x| page_number(input, x))
+                .collect::<Result<Vec<u64>, ParseError>>()?;
+
+            if HashSet::<&u64>::from_iter(&ls).len() != ls.len() {
+                return Err(ParseError::at(input, line, "pages without repetitions"));
+            }
+            update_lines.push(line);
+            updates.push(ls);
+        }
+    }
+
+    let rule_set = HashSet::from_iter(rules.iter().copied());
+    for (update, line) in updates.iter().zip(update_lines) {
+        if !has_unique_order(update, &rule_set) {
+            return Err(ParseError::at(
+                input,
+                line,
+                "pages the rules order in exactly one way",
+            ));
+        }
+    }
+
+    Ok((rules, updates))
+}
+
+/// Whether the rules between the pages of `update` admit exactly one order, as `order_correctly`
+/// requires. That is the case if the numbers of pages each page has to precede are all distinct.
+pub fn has_unique_order(update: &[u64], rules: &HashSet<(u64, u64)>) -> bool {
+    let mut precedes: Vec<usize> = update
+        .iter()
+        .map(|x| update.iter().filter(|y| rules.contains(&(*x, **y))).count())
+        .collect();
+    precedes.sort();
+    precedes.iter().enumerate().all(|(i, count)| i == *count)
+}
+
+pub fn page_number(input: &str, token: &str) -> Result<u64, ParseError> {
+    let page = parse_token(input, token, "a page number below 100")?;
+    if page >= 100 {
+        return Err(ParseError::at(input, token, "a page number below 100"));
+    }
+    Ok(page)
+}
+
+pub fn satisfies_rules(number_order: &[u64], edge_list: &[(u64, u64)], _debug_print: bool) -> bool {
+    let mut precondition_edges = Vec::new();
+    precondition_edges.resize(100, HashSet::new());
+    let mut edges = Vec::new();
+    edges.resize(100, HashSet::new());
+
+    for (from, to) in edge_list {
+        edges[*from as usize].insert(*to);
+        precondition_edges[*to as usize].insert(*from);
+    }
+
+    for x in number_order {
+        if !precondition_edges[*x as usize].is_empty() {
+            return false;
+        }
+        for to in edges[*x as usize].iter() {
+            precondition_edges[*to as usize].remove(x);
+        }
+    }
+
+    true
+}
+
+pub fn order_correctly(
+    vertices: Vec<u64>,
+    edge_list: Vec<(u64, u64)>,
+    debug_print: bool,
+) -> Vec<u64> {
+    let n = vertices.len();
+
+    let mut precondition_edges = Vec::new();
+    precondition_edges.resize(100, HashSet::new());
+    let mut edges = Vec::new();
+    edges.resize(100, HashSet::new());
+
+    let mut initially_empty: HashSet<&u64> = HashSet::from_iter(vertices.iter());
+
+    for (from, to) in edge_list {
+        edges[from as usize].insert(to);
+        precondition_edges[to as usize].insert(from);
+        initially_empty.remove(&to);
+    }
+
+    let mut solution = Vec::new();
+    assert_eq!(initially_empty.len(), 1);
+    solution.push(**initially_empty.iter().next().unwrap());
+
+    for pos in 0..n - 1 {
+        let x = solution[pos];
+        if debug_print {
+            log_debug!("solution: {:?}", solution);
+        }
+
+        let mut now_possible = Vec::new();
+        for to in edges[x as usize].clone() {
+            precondition_edges[to as usize].remove(&x);
+            edges[x as usize].remove(&to);
+            if precondition_edges[to as usize].is_empty() {
+                now_possible.push(to);
+            }
+        }
+        assert_eq!(now_possible.len(), 1);
+        for n in now_possible {
+            solution.push(n);
+        }
+    }
+
+    solution
+}
@@ -0,0 +1,24 @@
+use day_05::Day05;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day05 as Solver>::Parsed {
+    Day05.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day05.part1(&parsed()).to_string(), "143");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day05.part2(&parsed()).to_string(), "123");
+}
+
+#[test]
+fn cyclic_rules_are_rejected() {
+    let error = Day05.parse("1|2\n2|3\n3|1\n\n1,2,3\n").err().unwrap();
+    assert_eq!((error.line, error.column), (5, 1));
+}
@@ -0,0 +1,302 @@
+use std::collections::HashSet;
+use tae_aoclib2025::{
+    log_debug, log_enabled, Answer, Coordinate, Direction, Level, ParseError, Rng, Solver,
+};
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Parsed = Board;
+
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn name(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, board: &Self::Parsed) -> Answer {
+        let (all_visited_squares, _) = patrol(&mut board.clone(), false);
+        let result1 = all_visited_squares.len();
+        result1.into()
+    }
+
+    fn part2(&self, board: &Self::Parsed) -> Answer {
+        let (_, potential_new_obstacles) = patrol(&mut board.clone(), true);
+        let result2 = potential_new_obstacles.len();
+        result2.into()
+    }
+
+    /// A `size`x`size` lab with 5% obstacles and a guard that eventually leaves it.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        loop {
+            let mut lab = vec![vec!['.'; size]; size];
+            for square in lab.iter_mut().flatten() {
+                if rng.chance(0.05) {
+                    *square = '#';
+                }
+            }
+            lab[rng.below(size)][rng.below(size)] = '^';
+            let input: String = lab
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect();
+            // Labs trapping the guard in a loop do not parse
+            if parse_input(&input).is_ok() {
+                return input;
+            }
+        }
+    }
+}
+
+/// Walks the guard off the board. If `find_obstacles` is set, every square in front of the guard
+/// that was not visited yet is tested as a new obstacle that would trap the guard in a loop.
+pub fn patrol(
+    board: &mut Board,
+    find_obstacles: bool,
+) -> (HashSet<Coordinate>, HashSet<Coordinate>) {
+    let debug_print = log_enabled!(Level::Debug);
+
+    let mut history: Vec<Position> = Vec::new();
+
+    let mut all_visited_squares: HashSet<Coordinate> = HashSet::new();
+    let mut potential_new_obstacles = HashSet::new();
+
+    let mut cur_position = board.start_position;
+    all_visited_squares.insert(cur_position.coordinate);
+
+    loop {
+        if debug_print {
+            print_state(board, &history);
+        }
+        match step(board, &cur_position, debug_print) {
+            None => {
+                break;
+            }
+            Some(new_position) => {
+                cur_position = new_position;
+            }
+        }
+        history.push(cur_position);
+        all_visited_squares.insert(cur_position.coordinate);
+
+        if !find_obstacles {
+            continue;
+        }
+        if let Some(potential_obstacle) = get_next_square(board, &cur_position, debug_print) {
+            if !board.obstacles[potential_obstacle.row][potential_obstacle.col]
+                && !potential_new_obstacles.contains(&potential_obstacle)
+                && !all_visited_squares.contains(&potential_obstacle)
+            {
+                board.obstacles[potential_obstacle.row][potential_obstacle.col] = true;
+                if is_looping(board, cur_position, debug_print) {
+                    potential_new_obstacles.insert(potential_obstacle);
+                    if debug_print {
+                        print_state(board, &history);
+                    }
+                }
+                board.obstacles[potential_obstacle.row][potential_obstacle.col] = false;
+            }
+        }
+    }
+    (all_visited_squares, potential_new_obstacles)
+}
+
+pub fn is_looping(board: &Board, mut position: Position, debug_print: bool) -> bool {
+    let mut loop_position = position;
+    let mut steps_for_new_position = 100;
+    let mut last_steps_for_new_position = 100;
+    loop {
+        match step(board, &position, debug_print) {
+            None => {
+                return false;
+            }
+            Some(new_position) => {
+                position = new_position;
+                if position == loop_position {
+                    if debug_print {
+                        log_debug!("Loop!2");
+                    }
+                    return true;
+                }
+                steps_for_new_position -= 1;
+                if steps_for_new_position == 0 {
+                    loop_position = position;
+                    steps_for_new_position = last_steps_for_new_position * 2;
+                    last_steps_for_new_position = steps_for_new_position;
+                }
+            }
+        }
+    }
+}
+
+pub fn step(board: &Board, position: &Position, debug_print: bool) -> Option<Position> {
+    let next_square = get_next_square(board, position, debug_print);
+    if let Some(next_coord) = next_square {
+        if board.obstacles[next_coord.row][next_coord.col] {
+            Some(Position {
+                coordinate: position.coordinate,
+                direction: position.direction.turn_right(),
+            })
+        } else {
+            Some(Position {
+                coordinate: next_coord,
+                direction: position.direction,
+            })
+        }
+    } else {
+        None
+    }
+}
+
+pub fn get_next_square(
+    board: &Board,
+    position: &Position,
+    debug_print: bool,
+) -> Option<Coordinate> {
+    match position.direction {
+        Direction::Left => {
+            if position.coordinate.col == 0 {
+                if debug_print {
+                    log_debug!("Moved off the board - left.")
+                }
+                return None;
+            }
+            Some(Coordinate {
+                row: position.coordinate.row,
+                col: position.coordinate.col - 1,
+            })
+        }
+        Direction::Up => {
+            if position.coordinate.row == 0 {
+                if debug_print {
+                    log_debug!("Moved off the board - up.")
+                }
+                return None;
+            }
+            Some(Coordinate {
+                row: position.coordinate.row - 1,
+                col: position.coordinate.col,
+            })
+        }
+        Direction::Right => {
+            if position.coordinate.col == board.width - 1 {
+                if debug_print {
+                    log_debug!("Moved off the board - right.")
+                }
+                return None;
+            }
+            Some(Coordinate {
+                row: position.coordinate.row,
+                col: position.coordinate.col + 1,
+            })
+        }
+        Direction::Down => {
+            if position.coordinate.row == board.height - 1 {
+                if debug_print {
+                    log_debug!("Moved off the board - down.")
+                }
+                return None;
+            }
+            Some(Coordinate {
+                row: position.coordinate.row + 1,
+                col: position.coordinate.col,
+            })
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Board, ParseError> {
+    let width: usize = input.lines().next().map_or(0, |line| line.len());
+    let height: usize = input.lines().count();
+    let mut obstacles: Vec<Vec<bool>> = vec![vec![false; width]; height];
+    let mut start_position: Option<Position> = None;
+    for (row, line) in input.lines().enumerate() {
+        if line.len() != width {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a row of {width} squares"),
+            ));
+        }
+        for (col, c) in line.char_indices() {
+            match c {
+                '#' => obstacles[row][col] = true,
+                '^' if start_position.is_none() => {
+                    start_position = Some(Position {
+                        coordinate: Coordinate { row, col },
+                        direction: Direction::Up,
+                    })
+                }
+                '.' => {}
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        &line[col..col + c.len_utf8()],
+                        "`.`, `#` or a single `^`",
+                    ))
+                }
+            }
+        }
+    }
+    let start_position = start_position
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "the guard `^`"))?;
+    let board = Board {
+        width,
+        height,
+        obstacles,
+        start_position,
+    };
+    // Part 1 walks the guard until it leaves the lab
+    if is_looping(&board, start_position, false) {
+        let Coordinate { row, col } = start_position.coordinate;
+        let line = input.lines().nth(row).unwrap();
+        return Err(ParseError::at(
+            input,
+            &line[col..col + 1],
+            "a guard that leaves the lab",
+        ));
+    }
+    Ok(board)
+}
+
+fn print_state(board: &Board, history: &[Position]) {
+    let mut result = board
+        .obstacles
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|occupied| if *occupied { '#' } else { '.' })
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+    result[board.start_position.coordinate.row][board.start_position.coordinate.col] = '^';
+
+    for pos in history.iter() {
+        result[pos.coordinate.row][pos.coordinate.col] = pos.direction.into();
+    }
+
+    for line in result {
+        log_debug!("{}", line.iter().collect::<String>());
+    }
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
+pub struct Position {
+    pub coordinate: Coordinate,
+    pub direction: Direction,
+}
+
+#[derive(Clone)]
+pub struct Board {
+    pub width: usize,
+    pub height: usize,
+    pub obstacles: Vec<Vec<bool>>,
+    pub start_position: Position,
+}
@@ -0,0 +1,24 @@
+use day_06::Day06;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day06 as Solver>::Parsed {
+    Day06.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day06.part1(&parsed()).to_string(), "41");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day06.part2(&parsed()).to_string(), "6");
+}
+
+#[test]
+fn trapped_guard_is_rejected() {
+    let error = Day06.parse(".#.\n#^#\n.#.\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+}
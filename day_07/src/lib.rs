@@ -0,0 +1,190 @@
+use tae_aoclib2025::{
+    expect_split_once, log_debug, log_enabled, parse_token, Answer, Differential, Level,
+    ParseError, Rng, Solver,
+};
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Parsed = Vec<(u64, Vec<(u64, u32)>)>;
+
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn name(&self) -> &'static str {
+        "Bridge Repair"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (goal_str, numbers_str) = expect_split_once(input, line, ": ")?;
+                let numbers = numbers_str
+                    .split_whitespace()
+                    .map(|number_str| {
+                        // The reverse search divides by the numbers
+                        let number = parse_token(input, number_str, "a number")?;
+                        if number == 0 {
+                            return Err(ParseError::at(input, number_str, "a positive number"));
+                        }
+                        Ok((number, number_str.len() as u32))
+                    })
+                    .collect::<Result<Vec<(u64, u32)>, ParseError>>()?;
+                if numbers.is_empty() || numbers.len() >= 16 {
+                    return Err(ParseError::at(input, numbers_str, "1 to 15 numbers"));
+                }
+                Ok((parse_token(input, goal_str, "a number")?, numbers))
+            })
+            .collect()
+    }
+
+    fn part1(&self, equations: &Self::Parsed) -> Answer {
+        let mut result1 = 0;
+        for (goal, numbers) in equations {
+            let n = numbers.len();
+            assert!(n < 16);
+            for seed in 0..(1 << (n - 1)) {
+                if execute(numbers, seed) == *goal {
+                    result1 += goal;
+                    break;
+                }
+            }
+        }
+        result1.into()
+    }
+
+    fn part2(&self, equations: &Self::Parsed) -> Answer {
+        calibration_result(equations, true).into()
+    }
+
+    /// `size` equations of 2 to 9 numbers below 100 with results below 10^13. A third can be
+    /// solved with `+` and `*`, a third needs `||` and the rest is most likely unsolvable.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        let mut equations = 0;
+        while equations < size {
+            let numbers: Vec<u64> = (0..rng.range(2..=9))
+                .map(|_| rng.range(1..=99) as u64)
+                .collect();
+            let kind = rng.below(3);
+            let operators = if kind == 0 { 2 } else { 3 };
+            let mut goal = Some(numbers[0]);
+            for &number in &numbers[1..] {
+                goal = match (goal, rng.below(operators)) {
+                    (Some(goal), 0) => goal.checked_add(number),
+                    (Some(goal), 1) => goal.checked_mul(number),
+                    (Some(goal), _) => format!("{goal}{number}").parse().ok(),
+                    (None, _) => None,
+                };
+            }
+            let goal = match goal {
+                Some(goal) if goal < 10_000_000_000_000 => goal + u64::from(kind == 2),
+                _ => continue,
+            };
+            let numbers: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+            input.push_str(&format!("{goal}: {}\n", numbers.join(" ")));
+            equations += 1;
+        }
+        input
+    }
+
+    fn differentials(&self) -> Vec<Differential<Self::Parsed>> {
+        vec![Differential {
+            name: "part 1: brute force vs reverse search",
+            reference: |equations| Day07.part1(equations),
+            fast: |equations| calibration_result(equations, false).into(),
+        }]
+    }
+}
+
+/// Sum of the goals of the equations that can be solved, using the reverse search.
+pub fn calibration_result(equations: &[(u64, Vec<(u64, u32)>)], concatenation: bool) -> u128 {
+    let debug_print = log_enabled!(Level::Debug);
+    let mut result: u128 = 0;
+    for (goal, numbers) in equations {
+        if any_matches(numbers, *goal, concatenation, debug_print) {
+            result += *goal as u128;
+        }
+    }
+    result
+}
+
+/// Whether `goal` can be formed from `numbers` with `+`, `*` and, if `concatenation` is set, `||`.
+pub fn any_matches(
+    numbers: &[(u64, u32)],
+    goal: u64,
+    concatenation: bool,
+    debug_print: bool,
+) -> bool {
+    if debug_print {
+        log_debug!("Checking numbers: {:?}", numbers);
+    }
+    let mut possible_result_after_tries = vec![goal];
+    for (number, strlen) in numbers.iter().skip(1).rev() {
+        let mut new_possible_result_after_tries = Vec::new();
+        for result in possible_result_after_tries {
+            // Was it an add?
+            if result >= *number {
+                if debug_print {
+                    log_debug!(
+                        "{result} could have been formed by _{}_ + {number}",
+                        result - *number
+                    );
+                }
+                new_possible_result_after_tries.push(result - *number);
+            }
+
+            // Was it an mul?
+            if result % *number == 0 {
+                if debug_print {
+                    log_debug!(
+                        "{result} could have been formed by _{}_ * {number}",
+                        result / *number
+                    );
+                }
+                new_possible_result_after_tries.push(result / *number);
+            }
+
+            // Was it a concat?
+            if !concatenation {
+                continue;
+            }
+            let end_digit_exponent = 10_u64.pow(*strlen);
+            if result % end_digit_exponent == *number {
+                if debug_print {
+                    log_debug!(
+                        "{result} could have been formed by _{}_ || {number}",
+                        result / end_digit_exponent
+                    );
+                }
+                new_possible_result_after_tries.push(result / end_digit_exponent);
+            }
+        }
+        possible_result_after_tries = new_possible_result_after_tries;
+    }
+    for result in possible_result_after_tries {
+        if result == numbers.first().unwrap().0 {
+            if debug_print {
+                log_debug!("Found number {result} in final candidates!");
+            }
+            return true;
+        }
+    }
+    false
+}
+
+pub fn execute(numbers: &[(u64, u32)], mut seed: u64) -> u64 {
+    let mut numbers = numbers.iter();
+    let (mut result, _strlen) = *numbers.next().unwrap();
+    for (n, _strlen) in numbers {
+        if seed & 1 == 0 {
+            result += *n;
+        } else {
+            result *= *n;
+        }
+        seed >>= 1;
+    }
+    result
+}
@@ -0,0 +1,24 @@
+use day_07::Day07;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day07 as Solver>::Parsed {
+    Day07.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day07.part1(&parsed()).to_string(), "3749");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day07.part2(&parsed()).to_string(), "11387");
+}
+
+#[test]
+fn zero_operand_is_rejected() {
+    let error = Day07.parse("10: 5 0 2\n").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 7));
+}
@@ -0,0 +1,20 @@
+use day_07::Day07;
+use tae_aoclib2025::{find_disagreement, DynSolver, Solver};
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+#[test]
+fn differentials_agree_on_demo() {
+    let disagreements = Day07
+        .check_differentials(DEMO, &Default::default())
+        .unwrap();
+    assert!(disagreements.is_empty(), "{disagreements:?}");
+}
+
+#[test]
+fn differentials_agree_on_generated_inputs() {
+    assert!(!Day07.differentials().is_empty());
+    if let Some(finding) = find_disagreement(&Day07, 200, 10, 0) {
+        panic!("{:?}\n{}", finding.disagreements, finding.input);
+    }
+}
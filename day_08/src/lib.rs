@@ -0,0 +1,161 @@
+use std::collections::{HashMap, HashSet};
+use tae_aoclib2025::{log_debug, log_enabled, Answer, Level, ParseError, Rng, Solver};
+
+pub struct Day08;
+
+pub type Antinodes = HashSet<(usize, usize)>;
+
+pub struct AntennaMap {
+    pub rows: usize,
+    pub cols: usize,
+    pub antennas: HashMap<char, Vec<(usize, usize)>>,
+}
+
+impl Solver for Day08 {
+    type Parsed = AntennaMap;
+
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn name(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let rows = input.lines().count();
+        let cols = input.lines().next().map_or(0, |line| line.len());
+        if let Some(line) = input.lines().find(|line| line.len() != cols) {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a row of {cols} cells"),
+            ));
+        }
+
+        let mut antennas: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
+        for (row, line) in input.lines().enumerate() {
+            for (col, char) in line.chars().enumerate() {
+                if char != '.' {
+                    if let Some(x) = antennas.get_mut(&char) {
+                        x.push((row, col));
+                    } else {
+                        antennas.insert(char, vec![(row, col)]);
+                    }
+                }
+            }
+        }
+        Ok(AntennaMap {
+            rows,
+            cols,
+            antennas,
+        })
+    }
+
+    fn part1(&self, map: &Self::Parsed) -> Answer {
+        let (antinodes_strict, _) = find_antinodes(map, false);
+        let result1 = antinodes_strict.len() as i64;
+        result1.into()
+    }
+
+    fn part2(&self, map: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let (_, antinodes) = find_antinodes(map, debug_print);
+
+        if debug_print {
+            let mut debug_output: Vec<Vec<String>> =
+                vec![vec![".".to_string(); map.cols]; map.rows];
+            for (a_row, a_col) in &antinodes {
+                debug_output[*a_row][*a_col] = "#".to_string();
+            }
+            log_debug!(
+                "{:}",
+                debug_output
+                    .iter()
+                    .map(|x| x.join("") + "\n")
+                    .collect::<Vec<String>>()
+                    .join("")
+            );
+        }
+
+        let result2 = antinodes.len() as i64;
+        result2.into()
+    }
+
+    /// A `size`x`size` map with three or four antennas per frequency and one frequency per 60
+    /// cells, up to all 62 letters and digits.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let size = size.max(1);
+        let mut map = vec![vec!['.'; size]; size];
+        let frequencies = (size * size / 60).clamp(1, FREQUENCIES.len());
+        for frequency in FREQUENCIES.chars().take(frequencies) {
+            for _ in 0..rng.range(3..=4) {
+                map[rng.below(size)][rng.below(size)] = frequency;
+            }
+        }
+        map.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+/// Returns the antinodes at exactly twice the distance of an antenna pair and the antinodes
+/// at any multiple of the distance (including the antennas themselves).
+pub fn find_antinodes(map: &AntennaMap, debug_print: bool) -> (Antinodes, Antinodes) {
+    let rows = map.rows;
+    let cols = map.cols;
+    let mut antinodes = HashSet::new();
+    let mut antinodes_strict = HashSet::new();
+    for (char, antennas) in &map.antennas {
+        for antenna1 in antennas {
+            for antenna2 in antennas {
+                if antenna1 == antenna2 {
+                    continue;
+                }
+                let mut first: bool = true;
+                let mut antinode_row = antenna1.0;
+                let mut antinode_col = antenna1.1;
+                antinodes.insert((antinode_row, antinode_col));
+                loop {
+                    // Calculate antinode + (antenna1 - antenna2)
+                    if antenna2.0 > antinode_row + antenna1.0 {
+                        // Out-of-bounds up
+                        break;
+                    }
+                    if antenna2.1 > antinode_col + antenna1.1 {
+                        // Out-of-bounds left
+                        break;
+                    }
+                    // println!("{} {} {}", antinode_row, antenna1.0, antenna2.0);
+                    antinode_row = antinode_row + antenna1.0 - antenna2.0;
+                    antinode_col = antinode_col + antenna1.1 - antenna2.1;
+                    if antinode_row >= rows {
+                        // Out-of-bounds down
+                        break;
+                    }
+                    if antinode_col >= cols {
+                        // Out-of-bounds right
+                        break;
+                    }
+                    if debug_print {
+                        log_debug!(
+                            "{} antennas {:?} and {:?} map to {},{}",
+                            char,
+                            antenna1,
+                            antenna2,
+                            antinode_row,
+                            antinode_col
+                        );
+                    }
+                    if first {
+                        antinodes_strict.insert((antinode_row, antinode_col));
+                    }
+                    antinodes.insert((antinode_row, antinode_col));
+                    first = false;
+                }
+            }
+        }
+    }
+    (antinodes_strict, antinodes)
+}
@@ -0,0 +1,18 @@
+use day_08::Day08;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day08 as Solver>::Parsed {
+    Day08.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day08.part1(&parsed()).to_string(), "14");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day08.part2(&parsed()).to_string(), "34");
+}
@@ -0,0 +1,169 @@
+use tae_aoclib2025::{log_enabled, log_trace, Answer, Level, ParseError, Rng, Solver, TrimPolicy};
+
+pub struct Day09;
+
+/// The expanded disk (`usize::MAX` marks free space) and the `(size, position)` of every file
+/// and every free space span.
+pub type DiskLayout = (Vec<usize>, Vec<(usize, usize)>, Vec<(usize, usize)>);
+
+impl Solver for Day09 {
+    type Parsed = DiskLayout;
+
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn name(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+
+    fn trim_policy(&self) -> TrimPolicy {
+        TrimPolicy::TrimEnd
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let parsed = parse_input(input)?;
+        if log_enabled!(Level::Trace) {
+            log_trace!("{:?}", parsed.0);
+        }
+        Ok(parsed)
+    }
+
+    fn part1(&self, (disk, _, _): &Self::Parsed) -> Answer {
+        let fragmented_disk = compact_disk(disk);
+        let result1 = disk_hash(&fragmented_disk);
+        if log_enabled!(Level::Trace) {
+            log_trace!("{:?}", fragmented_disk);
+        }
+        result1.into()
+    }
+
+    fn part2(&self, (disk, files_index, free_space_index): &Self::Parsed) -> Answer {
+        let defragmented_disk = defrag(disk, files_index, free_space_index.clone());
+        let result2 = disk_hash(&defragmented_disk);
+        if log_enabled!(Level::Trace) {
+            log_trace!("{:?}", defragmented_disk);
+        }
+        result2.into()
+    }
+
+    /// A disk map of `size` digits, files of 1 to 9 blocks separated by 0 to 9 free blocks.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let mut input: String = (0..size.max(1))
+            .map(|i| {
+                let blocks = if i % 2 == 0 {
+                    rng.range(1..=9)
+                } else {
+                    rng.range(0..=9)
+                };
+                char::from_digit(blocks as u32, 10).unwrap()
+            })
+            .collect();
+        input.push('\n');
+        input
+    }
+}
+
+pub fn disk_hash(disk: &[usize]) -> usize {
+    let mut hash = 0;
+    for (pos, id) in disk.iter().enumerate() {
+        if *id == usize::MAX {
+            continue;
+        }
+        hash += id * pos;
+    }
+    hash
+}
+
+pub fn compact_disk(disk: &[usize]) -> Vec<usize> {
+    let mut fragmented_disk = disk.to_vec();
+    let mut free_cursor = 0;
+    let mut end_cursor = fragmented_disk.len() - 1;
+    while free_cursor < end_cursor {
+        if fragmented_disk[free_cursor] != usize::MAX {
+            free_cursor += 1;
+            continue;
+        }
+        if fragmented_disk[end_cursor] == usize::MAX {
+            end_cursor -= 1;
+            continue;
+        }
+        fragmented_disk[free_cursor] = fragmented_disk[end_cursor];
+        fragmented_disk[end_cursor] = usize::MAX;
+    }
+    fragmented_disk
+}
+
+pub fn defrag(
+    disk: &[usize],
+    files_index: &[(usize, usize)],
+    mut free_space_index: Vec<(usize, usize)>,
+) -> Vec<usize> {
+    let mut defragmented_disk = disk.to_vec();
+    for (file_size, idx_file) in files_index.iter().rev() {
+        let file_id = disk[*idx_file];
+        for (free_space_size, idx_free) in free_space_index.iter_mut() {
+            if *idx_free > *idx_file {
+                break;
+            }
+            if *file_size <= *free_space_size {
+                for i in 0..*file_size {
+                    defragmented_disk[*idx_free + i] = file_id;
+                    defragmented_disk[*idx_file + i] = usize::MAX;
+                }
+                *free_space_size -= *file_size;
+                *idx_free += *file_size;
+                break;
+            }
+        }
+    }
+    defragmented_disk
+}
+
+pub fn parse_input(input: &str) -> Result<DiskLayout, ParseError> {
+    let disk_map = input.trim_end();
+    if let Some((position, c)) = disk_map.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(ParseError::at(
+            input,
+            &disk_map[position..position + c.len_utf8()],
+            "a digit",
+        ));
+    }
+    if disk_map.is_empty() {
+        return Err(ParseError::at(input, disk_map, "a disk map"));
+    }
+    // Files of at least one block, the compaction relies on every file taking up space
+    if let Some((position, _)) = disk_map.char_indices().step_by(2).find(|(_, c)| *c == '0') {
+        return Err(ParseError::at(
+            input,
+            &disk_map[position..position + 1],
+            "a file of 1 to 9 blocks",
+        ));
+    }
+    let char_vec = disk_map.chars().collect::<Vec<char>>();
+
+    let mut disk = Vec::new();
+    let mut files_index = Vec::new();
+    let mut free_space_index = Vec::new();
+
+    for (id, x) in char_vec.chunks(2).enumerate() {
+        if x.len() == 2 {
+            let (files, empty) = (x[0] as usize - '0' as usize, x[1] as usize - '0' as usize);
+            files_index.push((files, disk.len()));
+            for _x in 0..files {
+                disk.push(id);
+            }
+            free_space_index.push((empty, disk.len()));
+            for _x in 0..empty {
+                disk.push(usize::MAX);
+            }
+        } else {
+            let files = x[0] as usize - '0' as usize;
+            files_index.push((files, disk.len()));
+            for _x in 0..files {
+                disk.push(id);
+            }
+        }
+    }
+    Ok((disk, files_index, free_space_index))
+}
@@ -0,0 +1,25 @@
+use day_09::Day09;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day09 as Solver>::Parsed {
+    Day09.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day09.part1(&parsed()).to_string(), "1928");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day09.part2(&parsed()).to_string(), "2858");
+}
+
+#[test]
+fn empty_files_are_rejected() {
+    assert!(Day09.parse("").is_err());
+    let error = Day09.parse("130").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 3));
+}
@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+use tae_aoclib2025::{log_enabled, log_trace, Answer, Level, ParseError, Rng, Solver};
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Parsed = Vec<Vec<usize>>;
+
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn name(&self) -> &'static str {
+        "Hoof It"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, board: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Trace);
+
+        let rows = board.len();
+        let cols = board[0].len();
+
+        let mut reachable_targets: Vec<Vec<HashSet<(usize, usize)>>> =
+            vec![vec![HashSet::new(); cols]; rows];
+        for row in 0..rows {
+            for col in 0..cols {
+                if board[row][col] == 9 {
+                    reachable_targets[row][col].insert((row, col));
+                }
+            }
+        }
+        for i in (0..9).rev() {
+            for row in 0..rows {
+                for col in 0..cols {
+                    if board[row][col] == i {
+                        for (n_row, n_col) in uphill_neighbors(board, row, col) {
+                            let reachable_from_neighbor: HashSet<(usize, usize)> =
+                                reachable_targets[n_row][n_col].iter().cloned().collect();
+                            reachable_targets[row][col].extend(reachable_from_neighbor);
+                        }
+                    }
+                }
+            }
+        }
+
+        if debug_print {
+            log_trace!(
+                "{:}",
+                board
+                    .iter()
+                    .map(|x| x
+                        .iter()
+                        .map(|c| format!("{c}"))
+                        .collect::<Vec<String>>()
+                        .join(""))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+            log_trace!(
+                "{:}",
+                reachable_targets
+                    .iter()
+                    .map(|x| x
+                        .iter()
+                        .map(|c| format!("{}", c.len()))
+                        .collect::<Vec<String>>()
+                        .join(""))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+        }
+
+        let mut result1 = 0;
+        for row in 0..rows {
+            for col in 0..cols {
+                if board[row][col] == 0 {
+                    result1 += reachable_targets[row][col].len();
+                }
+            }
+        }
+        result1.into()
+    }
+
+    fn part2(&self, board: &Self::Parsed) -> Answer {
+        let rows = board.len();
+        let cols = board[0].len();
+
+        let mut hiking_trails: Vec<Vec<usize>> = vec![vec![0; cols]; rows];
+        for row in 0..rows {
+            for col in 0..cols {
+                if board[row][col] == 9 {
+                    hiking_trails[row][col] = 1;
+                }
+            }
+        }
+        for i in (0..9).rev() {
+            for row in 0..rows {
+                for col in 0..cols {
+                    if board[row][col] == i {
+                        for (n_row, n_col) in uphill_neighbors(board, row, col) {
+                            hiking_trails[row][col] += hiking_trails[n_row][n_col];
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut result2 = 0;
+        for row in 0..rows {
+            for col in 0..cols {
+                if board[row][col] == 0 {
+                    result2 += hiking_trails[row][col];
+                }
+            }
+        }
+        result2.into()
+    }
+
+    /// A `size`x`size` map of random heights with one hiking trail from 0 to 9 carved into it
+    /// per 20 positions.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut map = vec![vec![0; size]; size];
+        for height in map.iter_mut().flatten() {
+            *height = rng.below(10);
+        }
+        for _ in 0..size * size / 20 {
+            let (mut row, mut col) = (rng.below(size), rng.below(size));
+            for height in 0..10 {
+                map[row][col] = height;
+                match rng.below(4) {
+                    0 if row > 0 => row -= 1,
+                    1 if row + 1 < size => row += 1,
+                    2 if col > 0 => col -= 1,
+                    3 if col + 1 < size => col += 1,
+                    _ => {}
+                }
+            }
+        }
+        map.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|height| char::from_digit(*height as u32, 10).unwrap())
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
+
+/// The neighbors (down, right, up, left) that are exactly one higher than `board[row][col]`.
+pub fn uphill_neighbors(board: &[Vec<usize>], row: usize, col: usize) -> Vec<(usize, usize)> {
+    let rows = board.len();
+    let cols = board[row].len();
+    let next = board[row][col] + 1;
+    let mut result = Vec::new();
+    if row + 1 < rows && board[row + 1][col] == next {
+        result.push((row + 1, col));
+    }
+    if col + 1 < cols && board[row][col + 1] == next {
+        result.push((row, col + 1));
+    }
+    if row >= 1 && board[row - 1][col] == next {
+        result.push((row - 1, col));
+    }
+    if col >= 1 && board[row][col - 1] == next {
+        result.push((row, col - 1));
+    }
+    result
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    let rows = input.lines().count();
+    let cols = input.lines().next().map_or(0, |line| line.len());
+    if rows == 0 || cols == 0 {
+        return Err(ParseError::at(input, input, "a height map"));
+    }
+    let mut board = vec![vec![0; cols]; rows];
+    for (row, line) in input.lines().enumerate() {
+        if line.len() != cols {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a row of {cols} digits"),
+            ));
+        }
+        for (col, c) in line.char_indices() {
+            board[row][col] = c
+                .to_digit(10)
+                .ok_or_else(|| ParseError::at(input, &line[col..col + c.len_utf8()], "a digit"))?
+                as usize;
+        }
+    }
+    Ok(board)
+}
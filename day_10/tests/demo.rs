@@ -0,0 +1,24 @@
+use day_10::Day10;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day10 as Solver>::Parsed {
+    Day10.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day10.part1(&parsed()).to_string(), "36");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day10.part2(&parsed()).to_string(), "81");
+}
+
+#[test]
+fn map_wider_than_tall() {
+    let parsed = Day10.parse("0123456789\n").unwrap();
+    assert_eq!(Day10.part1(&parsed).to_string(), "1");
+}
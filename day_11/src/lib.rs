@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use tae_aoclib2025::{
+    log_debug, log_enabled, parse_token, Answer, Level, Params, ParseError, Rng, Solver,
+};
+
+pub struct Day11;
+
+/// Blinks of part 1 and 2, configurable with the `part1_blinks` and `part2_blinks` params.
+pub const BLINKS: [usize; 2] = [25, 75];
+
+impl Solver for Day11 {
+    type Parsed = (HashMap<usize, usize>, [usize; 2]);
+
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn name(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        self.parse_with_params(input, &Params::default())
+    }
+
+    fn parse_with_params(&self, input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        let stones = input
+            .split_whitespace()
+            .map(|x| Ok((parse_token(input, x, "a number")?, 1)))
+            .collect::<Result<HashMap<usize, usize>, ParseError>>()?;
+        let blinks = [
+            params.get("part1_blinks", "a number")?.unwrap_or(BLINKS[0]),
+            params.get("part2_blinks", "a number")?.unwrap_or(BLINKS[1]),
+        ];
+        Ok((stones, blinks))
+    }
+
+    fn part1(&self, (stones, blinks): &Self::Parsed) -> Answer {
+        let result1 = blink(stones.clone(), blinks[0]).values().sum::<usize>();
+        result1.into()
+    }
+
+    fn part2(&self, (stones, blinks): &Self::Parsed) -> Answer {
+        let result2 = blink(stones.clone(), blinks[1]).values().sum::<usize>();
+        result2.into()
+    }
+
+    /// `size` stones with numbers below a million.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let stones: Vec<String> = (0..size.max(1))
+            .map(|_| rng.below(1_000_000).to_string())
+            .collect();
+        stones.join(" ") + "\n"
+    }
+}
+
+pub fn blink(mut stones: HashMap<usize, usize>, times: usize) -> HashMap<usize, usize> {
+    let debug_print = log_enabled!(Level::Debug);
+    if debug_print {
+        log_debug!("Start stones {:?}", stones);
+    }
+
+    for i in 0..times {
+        stones = step(stones);
+        if debug_print {
+            log_debug!("Step {i}: {stones:?}");
+        }
+    }
+    stones
+}
+
+pub fn step(stones: HashMap<usize, usize>) -> HashMap<usize, usize> {
+    let mut new_stones = HashMap::new();
+    for (stone, count) in &stones {
+        for new_stone in transform(*stone) {
+            if let Some(current) = new_stones.get_mut(&new_stone) {
+                *current += *count;
+            } else {
+                new_stones.insert(new_stone, *count);
+            }
+        }
+    }
+    new_stones
+}
+
+pub fn transform(number: usize) -> Vec<usize> {
+    if number == 0 {
+        return vec![1];
+    }
+    if let Some((first_half, second_half)) = split_even(number) {
+        return vec![first_half, second_half];
+    }
+    vec![number * 2024]
+}
+
+pub fn split_even(number: usize) -> Option<(usize, usize)> {
+    // let mut num_digits = 2;
+    // let mut lower = 10;
+    // let mut upper = 100; always lower * 10
+    // let mut half = 10;
+
+    // let mut num_digits = 4;
+    // let mut lower = 1000; -> lower *= 100
+    // let mut upper = 10000;
+    // let mut half = 100; -> half *= 10
+    let mut lower = 10;
+    let mut half_digits = 10;
+    loop {
+        let upper = lower * 10;
+        if number < lower {
+            return None;
+        }
+        assert!(lower <= number);
+        if number < upper {
+            return Some((number / half_digits, number % half_digits));
+        }
+        lower *= 100;
+        half_digits *= 10;
+    }
+}
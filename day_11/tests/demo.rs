@@ -0,0 +1,18 @@
+use day_11::Day11;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day11 as Solver>::Parsed {
+    Day11.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day11.part1(&parsed()).to_string(), "55312");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day11.part2(&parsed()).to_string(), "65601038650482");
+}
@@ -0,0 +1,259 @@
+use std::collections::{HashMap, VecDeque};
+use tae_aoclib2025::{log_enabled, log_trace, Answer, Level, ParseError, Rng, Solver};
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Parsed = Vec<Vec<char>>;
+
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn name(&self) -> &'static str {
+        "Garden Groups"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, board: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Trace);
+        let mut result1 = 0;
+        for region in find_regions(board).values() {
+            let price_1 = region.area * region.perimeter;
+            if debug_print {
+                log_trace!("{region:?} (Price: {price_1})");
+            }
+            result1 += price_1;
+        }
+        result1.into()
+    }
+
+    fn part2(&self, board: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Trace);
+        let mut result2 = 0;
+        for region in find_regions(board).values() {
+            let sides = region.sides();
+            let price_2 = region.area * sides;
+            if debug_print {
+                log_trace!("{region:?} (Sides: {sides}, Price2: {price_2})");
+            }
+            result2 += price_2;
+        }
+        result2.into()
+    }
+
+    /// A `size`x`size` garden of regions grown from random seeds, about 20 plots per region.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut garden = vec![vec!['.'; size]; size];
+        let mut queue = VecDeque::new();
+        for _ in 0..(size * size / 20).max(1) {
+            let (row, col) = (rng.below(size), rng.below(size));
+            garden[row][col] = *rng.choose(&('A'..='Z').collect::<Vec<char>>());
+            queue.push_back((row, col));
+        }
+        while let Some((row, col)) = queue.pop_front() {
+            let plant = garden[row][col];
+            let neighbors = [
+                (row.wrapping_sub(1), col),
+                (row + 1, col),
+                (row, col.wrapping_sub(1)),
+                (row, col + 1),
+            ];
+            for (row, col) in neighbors {
+                if row < size && col < size && garden[row][col] == '.' {
+                    garden[row][col] = plant;
+                    queue.push_back((row, col));
+                }
+            }
+        }
+        garden
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct Region {
+    pub area: usize,
+    pub perimeter: usize,
+    pub inside_corners: usize,
+    pub outside_corners: usize,
+}
+
+impl Region {
+    fn sides(&self) -> usize {
+        (self.inside_corners * 2) + self.outside_corners - self.inside_corners
+    }
+}
+
+pub fn find(
+    x: (usize, usize),
+    parents: &mut HashMap<(usize, usize), (usize, usize)>,
+) -> (usize, usize) {
+    let mut cur = x;
+    while cur != parents[&cur] {
+        cur = parents[&cur];
+    }
+    cur
+}
+
+pub fn find_regions(board: &[Vec<char>]) -> HashMap<(usize, usize), Region> {
+    let rows = board.len();
+    let cols = board[0].len();
+
+    let mut parents: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut ranks: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut regions: HashMap<(usize, usize), Region> = HashMap::new();
+
+    for row in 0..rows {
+        for col in 0..cols {
+            parents.insert((row, col), (row, col));
+            ranks.insert((row, col), 0);
+        }
+    }
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let character = board[row][col];
+            // Up
+            if row > 0 && board[row - 1][col] == character {
+                union((row, col), (row - 1, col), &mut parents, &mut ranks);
+            }
+            // Left
+            if col > 0 && board[row][col - 1] == character {
+                union((row, col), (row, col - 1), &mut parents, &mut ranks);
+            }
+            // Down
+            if row + 1 < rows && board[row + 1][col] == character {
+                union((row, col), (row + 1, col), &mut parents, &mut ranks);
+            }
+            // Right
+            if col + 1 < cols && board[row][col + 1] == character {
+                union((row, col), (row, col + 1), &mut parents, &mut ranks);
+            }
+        }
+    }
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let character = board[row][col];
+            let mut perimeter = 0;
+            let top_occupied = row > 0 && board[row - 1][col] == character;
+            if !top_occupied {
+                perimeter += 1;
+            }
+            let left_occupied = col > 0 && board[row][col - 1] == character;
+            if !left_occupied {
+                perimeter += 1;
+            }
+            let bot_occupied = row + 1 < rows && board[row + 1][col] == character;
+            if !bot_occupied {
+                perimeter += 1;
+            }
+            let right_occupied = col + 1 < cols && board[row][col + 1] == character;
+            if !right_occupied {
+                perimeter += 1;
+            }
+
+            let mut inside_corners = 0;
+            if top_occupied && left_occupied && board[row - 1][col - 1] != character {
+                inside_corners += 1;
+            }
+            if top_occupied && right_occupied && board[row - 1][col + 1] != character {
+                inside_corners += 1;
+            }
+            if bot_occupied && left_occupied && board[row + 1][col - 1] != character {
+                inside_corners += 1;
+            }
+            if bot_occupied && right_occupied && board[row + 1][col + 1] != character {
+                inside_corners += 1;
+            }
+
+            let mut outside_corners = 0;
+            if perimeter == 4 {
+                outside_corners = 4;
+            } else if perimeter == 3 {
+                outside_corners = 2;
+            } else if perimeter == 2 {
+                if (top_occupied && bot_occupied) || (left_occupied && right_occupied) {
+                    // Nothing
+                } else {
+                    outside_corners = 1;
+                }
+            }
+
+            let region_key = find((row, col), &mut parents);
+            if let Some(region) = regions.get_mut(&region_key) {
+                region.perimeter += perimeter;
+                region.area += 1;
+                region.inside_corners += inside_corners;
+                region.outside_corners += outside_corners;
+            } else {
+                regions.insert(
+                    region_key,
+                    Region {
+                        area: 1,
+                        perimeter,
+                        inside_corners,
+                        outside_corners,
+                    },
+                );
+            }
+        }
+    }
+
+    regions
+}
+
+pub fn union(
+    mut x: (usize, usize),
+    mut y: (usize, usize),
+    parents: &mut HashMap<(usize, usize), (usize, usize)>,
+    ranks: &mut HashMap<(usize, usize), usize>,
+) {
+    x = find(x, parents);
+    y = find(y, parents);
+    if x == y {
+        return;
+    }
+    if ranks[&x] < ranks[&y]
+    // Wenn der Rang von x kleiner als der Rang von y ist, wird y zur neuen Wurzel
+    {
+        parents.insert(x, y);
+    } else if ranks[&x] > ranks[&y]
+    // Wenn der Rang von x größer als der Rang von y ist, wird x zur neuen Wurzel
+    {
+        parents.insert(y, x);
+    } else
+    // Wenn die Ränge gleich sind, wird y zur neuen Wurzel und der Rang von y inkrementiert
+    {
+        parents.insert(x, y);
+        ranks.insert(y, ranks.get(&y).unwrap() + 1);
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let rows = input.lines().count();
+    let cols = input.lines().next().map_or(0, |line| line.chars().count());
+    let mut board: Vec<Vec<char>> = vec![vec!['.'; cols]; rows];
+
+    for (row, line) in input.lines().enumerate() {
+        if line.chars().count() != cols {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a row of {cols} plots"),
+            ));
+        }
+        for (col, c) in line.chars().enumerate() {
+            board[row][col] = c;
+        }
+    }
+
+    Ok(board)
+}
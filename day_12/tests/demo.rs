@@ -0,0 +1,18 @@
+use day_12::Day12;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day12 as Solver>::Parsed {
+    Day12.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day12.part1(&parsed()).to_string(), "1930");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day12.part2(&parsed()).to_string(), "1206");
+}
@@ -0,0 +1,375 @@
+use std::cmp::{max, min};
+use tae_aoclib2025::{
+    expect_next, expect_prefix, expect_split_once, log_debug, log_enabled, parse_token, Answer,
+    Differential, Level, Params, ParseError, Rng, Solver,
+};
+
+/// Added to the prize coordinates in part 2, configurable with the `prize_offset` param.
+pub const PRIZE_OFFSET: usize = 10000000000000;
+
+/// The highest cost `min_cost` tries.
+pub const MAX_BRUTE_FORCE_COST: usize = 400;
+
+pub struct Day13;
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub a: (usize, usize),
+    pub b: (usize, usize),
+    pub prize: (usize, usize),
+}
+
+impl Solver for Day13 {
+    type Parsed = (Vec<Game>, usize);
+
+    fn day(&self) -> u32 {
+        13
+    }
+
+    fn name(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        self.parse_with_params(input, &Params::default())
+    }
+
+    fn parse_with_params(&self, input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        let prize_offset = params.get("prize_offset", "a number")?;
+        Ok((parse_input(input)?, prize_offset.unwrap_or(PRIZE_OFFSET)))
+    }
+
+    fn part1(&self, (games, _): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let mut result1 = 0;
+
+        for game in games {
+            if let Some(cost) = min_cost(game) {
+                if debug_print {
+                    log_debug!("{game:?} cost is {cost}");
+                }
+                result1 += cost;
+            }
+        }
+        result1.into()
+    }
+
+    fn part2(&self, (games, prize_offset): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let real_games = games.iter().map(|game| Game {
+            prize: (game.prize.0 + prize_offset, game.prize.1 + prize_offset),
+            ..game.clone()
+        });
+
+        let mut result2 = 0;
+        for game in real_games {
+            if let Some((a_presses, b_presses)) = solve_large(&game, debug_print) {
+                let cost = a_presses * 3 + b_presses;
+                if debug_print {
+                    log_debug!("{game:?} cost is {cost} by pressing a {a_presses} times and b {b_presses} times.");
+                }
+                assert_eq!(a_presses * game.a.0 + b_presses * game.b.0, game.prize.0);
+                assert_eq!(a_presses * game.a.1 + b_presses * game.b.1, game.prize.1);
+                result2 += cost;
+            } else if debug_print {
+                log_debug!("Unsolvable {game:?}. For reasons, see above.");
+            }
+        }
+        result2.into()
+    }
+
+    /// `size` claw machines with buttons moving 10 to 99 along each axis, or collinear buttons
+    /// for about a tenth of them. About half of them are won with at most 100 presses per button.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let mut machines = Vec::new();
+        for _ in 0..size {
+            let (a, b) = if rng.chance(0.1) {
+                let direction = (rng.range(1..=9), rng.range(1..=9));
+                let (a_scale, b_scale) = (rng.range(1..=11), rng.range(1..=11));
+                (
+                    (direction.0 * a_scale, direction.1 * a_scale),
+                    (direction.0 * b_scale, direction.1 * b_scale),
+                )
+            } else {
+                (
+                    (rng.range(10..=99), rng.range(10..=99)),
+                    (rng.range(10..=99), rng.range(10..=99)),
+                )
+            };
+            let (a_presses, b_presses) = (rng.range(1..=100), rng.range(1..=100));
+            let mut prize = (
+                a.0 * a_presses + b.0 * b_presses,
+                a.1 * a_presses + b.1 * b_presses,
+            );
+            if rng.chance(0.5) {
+                prize.0 += rng.range(1..=50);
+            }
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            ));
+        }
+        machines.join("\n")
+    }
+
+    /// `solve_large` is meant for the prizes of part 2, which the brute force cannot reach, so
+    /// both are compared on the unshifted prizes. Only costs the brute force would find count.
+    fn differentials(&self) -> Vec<Differential<Self::Parsed>> {
+        vec![Differential {
+            name: "brute force vs solve_large without prize offset",
+            reference: |(games, _)| games.iter().filter_map(min_cost).sum::<usize>().into(),
+            fast: |(games, _)| {
+                games
+                    .iter()
+                    .filter_map(|game| solve_large(game, false))
+                    .map(|(a_presses, b_presses)| a_presses * 3 + b_presses)
+                    .filter(|&cost| cost <= MAX_BRUTE_FORCE_COST)
+                    .sum::<usize>()
+                    .into()
+            },
+        }]
+    }
+}
+
+/// Whether the buttons move in different relative directions, as `solve_large` assumes.
+pub fn fits_solve_large(game: &Game) -> bool {
+    game.a.0 != game.a.1 && game.b.0 != game.b.1 && (game.a.0 > game.a.1) != (game.b.0 > game.b.1)
+}
+
+// Assumes very big and almost equal prize positions, falls back to `solve_exact` otherwise
+pub fn solve_large(game: &Game, debug_print: bool) -> Option<(usize, usize)> {
+    // Buttons moving along the diagonal or in the same relative direction lose balance on big
+    // prizes, but not necessarily on small ones
+    if !fits_solve_large(game) {
+        if debug_print {
+            log_debug!("Buttons do not move in different relative directions, solving exactly.");
+        }
+        return solve_exact(game);
+    }
+
+    let a_diff = game.a.0 as i64 - game.a.1 as i64;
+    let b_diff = game.b.0 as i64 - game.b.1 as i64;
+
+    let prize_diff = game.prize.0 as i64 - game.prize.1 as i64;
+
+    if let Some((a_presses, b_presses)) = reach_diff(a_diff, b_diff, prize_diff) {
+        let coords_after_diff_reached = (
+            a_presses * game.a.0 + b_presses * game.b.0,
+            a_presses * game.a.1 + b_presses * game.b.1,
+        );
+
+        // Negative if reaching the diff overshot a small prize, then cycles are taken back
+        let x_distance_left = game.prize.0 as i64 - coords_after_diff_reached.0 as i64;
+        let y_distance_left = game.prize.1 as i64 - coords_after_diff_reached.1 as i64;
+
+        let (a_presses_cycle, b_presses_cycle) =
+            get_neutral_presses(a_diff.unsigned_abs(), b_diff.unsigned_abs());
+        let x_distance_covered_per_cycle =
+            (a_presses_cycle * game.a.0 + b_presses_cycle * game.b.0) as i64;
+        let y_distance_covered_per_cycle =
+            (a_presses_cycle * game.a.1 + b_presses_cycle * game.b.1) as i64;
+
+        if x_distance_left % x_distance_covered_per_cycle != 0 {
+            if debug_print {
+                log_debug!("Unsolvable, the remaining distance ({x_distance_left}, {y_distance_left}) after pressing a {a_presses} times and b {b_presses} times cannot be covered by cycling our neutral distance ({x_distance_covered_per_cycle}, {y_distance_covered_per_cycle}), which requires {a_presses_cycle} presses of button a and {b_presses_cycle} presses of button b.");
+            }
+            return None;
+        }
+
+        let cycles_for_x_distance = x_distance_left / x_distance_covered_per_cycle;
+        let cycles_for_y_distance = y_distance_left / y_distance_covered_per_cycle;
+
+        // Equal, as the diff was reached and a cycle moves equally far along both axes
+        debug_assert_eq!(cycles_for_x_distance, cycles_for_y_distance);
+        let cycles = cycles_for_x_distance;
+
+        let total_a_presses = cycles * a_presses_cycle as i64 + a_presses as i64;
+        let total_b_presses = cycles * b_presses_cycle as i64 + b_presses as i64;
+        if total_a_presses < 0 || total_b_presses < 0 {
+            if debug_print {
+                log_debug!(
+                    "Unsolvable, taking back {} cycles needs more presses than were made.",
+                    -cycles
+                );
+            }
+            return None;
+        }
+
+        return Some((total_a_presses as usize, total_b_presses as usize));
+    }
+    if debug_print {
+        log_debug!("Did not converge to target diff ({prize_diff}) in time! It should (!!) be impossible with the numbers {a_diff} and {b_diff}.");
+    }
+    None
+}
+
+pub fn reach_diff(a_diff: i64, b_diff: i64, prize_diff: i64) -> Option<(usize, usize)> {
+    // Solve a_diff * a_presses + b_diff * b_presses = prize_diff
+    let positive_diff = max(a_diff, b_diff);
+    let mut positive_presses = 0;
+    let negative_diff = min(a_diff, b_diff);
+    let mut negative_presses = 0;
+
+    let mut cur = 0;
+    for _ in 0..(prize_diff.abs() * 2 + 1) * 1000 {
+        if cur == prize_diff {
+            return if a_diff < 0 {
+                Some((negative_presses, positive_presses))
+            } else {
+                Some((positive_presses, negative_presses))
+            };
+        }
+        if cur < prize_diff {
+            positive_presses += 1;
+            cur += positive_diff;
+        }
+        if cur > prize_diff {
+            negative_presses += 1;
+            cur += negative_diff;
+        }
+    }
+    None
+}
+
+/// Solves the two equations of a game with Cramer's rule. Collinear buttons leave a single
+/// equation, whose solutions trade presses of one button for the other, so the cheapest one has
+/// the fewest presses of either button.
+pub fn solve_exact(game: &Game) -> Option<(usize, usize)> {
+    let (a_x, a_y) = (game.a.0 as i128, game.a.1 as i128);
+    let (b_x, b_y) = (game.b.0 as i128, game.b.1 as i128);
+    let (prize_x, prize_y) = (game.prize.0 as i128, game.prize.1 as i128);
+    let determinant = a_x * b_y - a_y * b_x;
+    if determinant != 0 {
+        let a_presses = prize_x * b_y - prize_y * b_x;
+        let b_presses = a_x * prize_y - a_y * prize_x;
+        if a_presses % determinant != 0 || b_presses % determinant != 0 {
+            return None;
+        }
+        let (a_presses, b_presses) = (a_presses / determinant, b_presses / determinant);
+        if a_presses < 0 || b_presses < 0 {
+            return None;
+        }
+        return Some((a_presses as usize, b_presses as usize));
+    }
+
+    // Solve the equation of an axis the buttons move along, it holds for the other one as well
+    // unless the prize is off their line
+    let ((a_move, b_move), prize) = if a_x != 0 || b_x != 0 {
+        ((a_x, b_x), prize_x)
+    } else if a_y != 0 || b_y != 0 {
+        ((a_y, b_y), prize_y)
+    } else {
+        return (game.prize == (0, 0)).then_some((0, 0));
+    };
+    let fewest_a = fewest_presses(a_move, b_move, prize);
+    let fewest_b = fewest_presses(b_move, a_move, prize).map(|(b, a)| (a, b));
+    [fewest_a, fewest_b]
+        .into_iter()
+        .flatten()
+        .filter(|(a, b)| a * a_x + b * b_x == prize_x && a * a_y + b * b_y == prize_y)
+        .min_by_key(|(a, b)| a * 3 + b)
+        .map(|(a, b)| (a as usize, b as usize))
+}
+
+/// The solution of `first * first_presses + second * second_presses = prize` with the fewest
+/// presses of the first button, both moves being non-negative and not both zero.
+pub fn fewest_presses(first: i128, second: i128, prize: i128) -> Option<(i128, i128)> {
+    if second == 0 {
+        return (prize % first == 0).then_some((prize / first, 0));
+    }
+    if first == 0 {
+        return (prize % second == 0).then_some((0, prize / second));
+    }
+    let divisor = gcd(first as u64, second as u64) as i128;
+    if prize % divisor != 0 {
+        return None;
+    }
+    // The first presses are determined modulo `second / divisor`
+    let modulus = second / divisor;
+    let first_presses =
+        (prize / divisor % modulus) * mod_inverse(first / divisor % modulus, modulus) % modulus;
+    let second_presses = (prize - first * first_presses) / second;
+    (second_presses >= 0).then_some((first_presses, second_presses))
+}
+
+/// The inverse of `value` modulo `modulus`, which must be coprime, by the extended Euclidean
+/// algorithm.
+pub fn mod_inverse(value: i128, modulus: i128) -> i128 {
+    let (mut old_remainder, mut remainder) = (value, modulus);
+    let (mut old_coefficient, mut coefficient) = (1, 0);
+    while remainder != 0 {
+        let quotient = old_remainder / remainder;
+        (old_remainder, remainder) = (remainder, old_remainder - quotient * remainder);
+        (old_coefficient, coefficient) = (coefficient, old_coefficient - quotient * coefficient);
+    }
+    old_coefficient.rem_euclid(modulus)
+}
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    (a * b) / gcd(a, b)
+}
+
+pub fn get_neutral_presses(a_diff_abs: u64, b_diff_abs: u64) -> (usize, usize) {
+    let lcm = lcm(a_diff_abs, b_diff_abs);
+    ((lcm / a_diff_abs) as usize, (lcm / b_diff_abs) as usize)
+}
+
+pub fn min_cost(game: &Game) -> Option<usize> {
+    (1..=MAX_BRUTE_FORCE_COST).find(|&budget| try_budget(game, budget))
+}
+
+pub fn try_budget(game: &Game, budget: usize) -> bool {
+    for a_presses in 0..(budget / 3) + 1 {
+        let b_presses = budget - a_presses * 3;
+        if try_game(game, a_presses, b_presses) {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn try_game(game: &Game, a_presses: usize, b_presses: usize) -> bool {
+    let mut current = (0, 0);
+    current.0 = a_presses * game.a.0 + b_presses * game.b.0;
+    current.1 = a_presses * game.a.1 + b_presses * game.b.1;
+    current == game.prize
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    let mut games: Vec<Game> = Vec::new();
+
+    let mut input_lines = input.lines();
+
+    while let Some(a_line) = input_lines.next() {
+        let b_line = expect_next(input, input, &mut input_lines, "a `Button B: ` line")?;
+        let prize_line = expect_next(input, input, &mut input_lines, "a `Prize: ` line")?;
+        input_lines.next();
+
+        games.push(Game {
+            a: parse_pair(input, expect_prefix(input, a_line, "Button A: ")?, '+')?,
+            b: parse_pair(input, expect_prefix(input, b_line, "Button B: ")?, '+')?,
+            prize: parse_pair(input, expect_prefix(input, prize_line, "Prize: ")?, '=')?,
+        });
+    }
+
+    Ok(games)
+}
+
+/// Parses `X+94, Y+34` (or `X=8400, Y=5400` with `sign` `=`).
+pub fn parse_pair(input: &str, text: &str, sign: char) -> Result<(usize, usize), ParseError> {
+    let (x, y) = expect_split_once(input, text, ", ")?;
+    let x = expect_prefix(input, x, &format!("X{sign}"))?;
+    let y = expect_prefix(input, y, &format!("Y{sign}"))?;
+    Ok((
+        parse_token(input, x, "a number")?,
+        parse_token(input, y, "a number")?,
+    ))
+}
@@ -0,0 +1,50 @@
+use day_13::{solve_large, Day13, Game};
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day13 as Solver>::Parsed {
+    Day13.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day13.part1(&parsed()).to_string(), "480");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day13.part2(&parsed()).to_string(), "875318608908");
+}
+
+#[test]
+fn diagonal_button() {
+    let game = Game {
+        a: (5, 5),
+        b: (2, 3),
+        prize: (12, 13),
+    };
+    assert_eq!(solve_large(&game, false), Some((2, 1)));
+}
+
+#[test]
+fn collinear_buttons() {
+    let game = Game {
+        a: (2, 4),
+        b: (1, 2),
+        prize: (4, 8),
+    };
+    assert_eq!(solve_large(&game, false), Some((0, 4)));
+    let game = Game {
+        a: (4, 4),
+        b: (1, 1),
+        prize: (8, 8),
+    };
+    assert_eq!(solve_large(&game, false), Some((2, 0)));
+    let game = Game {
+        a: (2, 4),
+        b: (1, 2),
+        prize: (4, 9),
+    };
+    assert_eq!(solve_large(&game, false), None);
+}
@@ -0,0 +1,20 @@
+use day_13::Day13;
+use tae_aoclib2025::{find_disagreement, DynSolver, Solver};
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+#[test]
+fn differentials_agree_on_demo() {
+    let disagreements = Day13
+        .check_differentials(DEMO, &Default::default())
+        .unwrap();
+    assert!(disagreements.is_empty(), "{disagreements:?}");
+}
+
+#[test]
+fn differentials_agree_on_generated_inputs() {
+    assert!(!Day13.differentials().is_empty());
+    if let Some(finding) = find_disagreement(&Day13, 200, 10, 0) {
+        panic!("{:?}\n{}", finding.disagreements, finding.input);
+    }
+}
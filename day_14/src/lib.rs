@@ -0,0 +1,227 @@
+use tae_aoclib2025::{
+    expect_prefix, expect_split_once, log_enabled, log_trace, parse_token, Answer, Coordinate,
+    Level, Params, ParseError, Rng, Solver,
+};
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Parsed = (usize, usize, Vec<Robot>);
+
+    fn day(&self) -> u32 {
+        14
+    }
+
+    fn name(&self) -> &'static str {
+        "Restroom Redoubt"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        self.parse_with_params(input, &Params::default())
+    }
+
+    fn parse_with_params(&self, input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        // The example is 11x7 while real inputs (with way more robots) are 101x103
+        let (default_cols, default_rows) = if input.lines().count() > 30 {
+            (101, 103)
+        } else {
+            (11, 7)
+        };
+        let cols = params.get("width", "a width")?.unwrap_or(default_cols);
+        let rows = params.get("height", "a height")?.unwrap_or(default_rows);
+        parse_input(input, cols, rows)
+    }
+
+    fn part1(&self, (cols, rows, robots): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Trace);
+        let (cols, rows) = (*cols, *rows);
+        let mut positions: Vec<Coordinate> = robots.iter().map(|robot| robot.position).collect();
+        for _ in 0..100 {
+            move_robots(&mut positions, robots, cols, rows);
+        }
+        let result1 = calculate_safety_number(&positions, cols, rows, debug_print);
+        result1.into()
+    }
+
+    fn part2(&self, (cols, rows, robots): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Trace);
+        let (cols, rows) = (*cols, *rows);
+
+        let step_limit = 50000;
+        let mut result2 = 0;
+
+        let mut positions: Vec<Coordinate> = robots.iter().map(|robot| robot.position).collect();
+        for step in 0..step_limit {
+            move_robots(&mut positions, robots, cols, rows);
+            if is_christmas_tree(&positions, cols, rows, step, debug_print) {
+                result2 = step + 1;
+                break;
+            }
+        }
+
+        if debug_print {
+            print_positions(&positions, cols, rows);
+        }
+
+        result2.into()
+    }
+
+    /// `size` robots, in the 11x7 space of the example for up to 30 robots and 101x103 beyond.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let (cols, rows) = if size > 30 { (101, 103) } else { (11, 7) };
+        let mut input = String::new();
+        for _ in 0..size {
+            let (col, row) = (rng.below(cols), rng.below(rows));
+            let vel_col = rng.range(0..=2 * cols - 2) as i64 - (cols as i64 - 1);
+            let vel_row = rng.range(0..=2 * rows - 2) as i64 - (rows as i64 - 1);
+            input.push_str(&format!("p={col},{row} v={vel_col},{vel_row}\n"));
+        }
+        input
+    }
+}
+
+pub fn move_robots(positions: &mut [Coordinate], robots: &[Robot], cols: usize, rows: usize) {
+    for (position, robot) in positions.iter_mut().zip(robots) {
+        *position += robot.vel;
+        *position %= Coordinate {
+            col: cols,
+            row: rows,
+        };
+    }
+}
+
+fn print_positions(positions: &[Coordinate], cols: usize, rows: usize) {
+    let mut robots_at_position = vec![vec![0; cols]; rows];
+    for pos in positions {
+        robots_at_position[pos.row][pos.col] += 1;
+    }
+    log_trace!(
+        "All robots:\n{}",
+        robots_at_position
+            .iter()
+            .map(|row| row
+                .iter()
+                .map(|c| format!("{c}"))
+                .collect::<Vec<String>>()
+                .join(""))
+            .collect::<Vec<String>>()
+            .join("\n")
+    );
+}
+
+pub fn is_christmas_tree(
+    positions: &[Coordinate],
+    cols: usize,
+    rows: usize,
+    step: usize,
+    debug_print: bool,
+) -> bool {
+    let mut row_cols: Vec<Vec<usize>> = vec![Vec::new(); rows];
+    positions
+        .iter()
+        .for_each(|pos| row_cols[pos.row].push(pos.col));
+    let mut horizontal_rows = 0;
+    for mut col_ids in row_cols {
+        col_ids.sort_unstable();
+        for i in 3..col_ids.len() {
+            if col_ids[i - 3] + 1 == col_ids[i - 2]
+                && col_ids[i - 2] + 1 == col_ids[i - 1]
+                && col_ids[i - 1] + 1 == col_ids[i]
+            {
+                horizontal_rows += 1;
+            }
+        }
+    }
+    if horizontal_rows >= 20 {
+        if debug_print {
+            log_trace!("Step {}", step + 1);
+            print_positions(positions, cols, rows);
+        }
+        true
+    } else {
+        false
+    }
+}
+
+pub fn calculate_safety_number(
+    positions: &[Coordinate],
+    cols: usize,
+    rows: usize,
+    debug_print: bool,
+) -> i64 {
+    let mut top_left = 0;
+    let mut top_right = 0;
+    let mut bot_left = 0;
+    let mut bot_right = 0;
+    for pos in positions {
+        if debug_print {
+            log_trace!("A robot will be at position {pos:?}");
+        }
+        if pos.col < cols / 2 && pos.row < rows / 2 {
+            if debug_print {
+                log_trace!("This is in quadrant top_left");
+            }
+            top_left += 1;
+        } else if pos.col > cols / 2 && pos.row < rows / 2 {
+            if debug_print {
+                log_trace!("This is in quadrant top_right");
+            }
+            top_right += 1;
+        } else if pos.col < cols / 2 && pos.row > rows / 2 {
+            if debug_print {
+                log_trace!("This is in quadrant bot_left");
+            }
+            bot_left += 1;
+        } else if pos.col > cols / 2 && pos.row > rows / 2 {
+            if debug_print {
+                log_trace!("This is in quadrant bot_right");
+            }
+            bot_right += 1;
+        }
+    }
+    top_left * top_right * bot_left * bot_right
+}
+
+#[derive(Debug)]
+pub struct Robot {
+    pub position: Coordinate,
+    pub vel: Coordinate,
+}
+
+pub fn parse_input(
+    input: &str,
+    cols: usize,
+    rows: usize,
+) -> Result<(usize, usize, Vec<Robot>), ParseError> {
+    let mut result = Vec::new();
+    for line in input.lines() {
+        let (position, velocity) = expect_split_once(input, line, " ")?;
+        let position = expect_prefix(input, position, "p=")?;
+        let velocity = expect_prefix(input, velocity, "v=")?;
+        let (col, row) = expect_split_once(input, position, ",")?;
+        let (col, row): (usize, usize) = (
+            parse_token(input, col, "a column")?,
+            parse_token(input, row, "a row")?,
+        );
+        if col >= cols || row >= rows {
+            return Err(ParseError::at(
+                input,
+                position,
+                format!("a position within {cols}x{rows}"),
+            ));
+        }
+        let (vel_col, vel_row) = expect_split_once(input, velocity, ",")?;
+        let (vel_col, vel_row): (i64, i64) = (
+            parse_token(input, vel_col, "a velocity")?,
+            parse_token(input, vel_row, "a velocity")?,
+        );
+        result.push(Robot {
+            position: Coordinate { col, row },
+            vel: Coordinate {
+                col: vel_col.rem_euclid(cols as i64) as usize,
+                row: vel_row.rem_euclid(rows as i64) as usize,
+            },
+        });
+    }
+    Ok((cols, rows, result))
+}
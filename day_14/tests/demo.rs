@@ -0,0 +1,18 @@
+use day_14::Day14;
+use tae_aoclib2025::{Params, Solver};
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day14 as Solver>::Parsed {
+    Day14
+        .parse_with_params(
+            DEMO,
+            &Params::parse(include_str!("../input/demo.params")).unwrap(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day14.part1(&parsed()).to_string(), "12");
+}
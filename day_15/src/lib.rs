@@ -0,0 +1,496 @@
+use std::fmt::{Display, Formatter};
+use tae_aoclib2025::{
+    log_debug, log_enabled, step, Answer, Coordinate, Direction, Level, ParseError, Rng, Solver,
+};
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Parsed = (MapState, Vec<Direction>);
+
+    fn day(&self) -> u32 {
+        15
+    }
+
+    fn name(&self) -> &'static str {
+        "Warehouse Woes"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (start_map, movements): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        if debug_print {
+            log_debug!("Initial state:");
+            print_map(&start_map.map);
+        }
+
+        let mut map = start_map.clone();
+        for movement in movements {
+            map = next(map, movement);
+            if debug_print {
+                log_debug!("Move {movement}:");
+                print_map(&map.map);
+            }
+        }
+
+        let mut result1 = 0;
+        for (row, line) in map.map.iter().enumerate() {
+            for (col, object) in line.iter().enumerate() {
+                if *object == Object::Box {
+                    result1 += row * 100 + col;
+                }
+            }
+        }
+        result1.into()
+    }
+
+    fn part2(&self, (start_map, movements): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let mut wide_map = start_map.widen();
+        if debug_print {
+            log_debug!("W Initial state:");
+            print_wide_map(&wide_map.map);
+        }
+        for movement in movements {
+            wide_map = next_wide(wide_map, movement);
+            if debug_print {
+                log_debug!("W Move {movement}:");
+                print_wide_map(&wide_map.map);
+            }
+        }
+
+        let mut result2 = 0;
+        for (row, line) in wide_map.map.iter().enumerate() {
+            for (col, object) in line.iter().enumerate() {
+                if *object == WideObject::BoxLeft {
+                    result2 += row * 100 + col;
+                }
+            }
+        }
+        result2.into()
+    }
+
+    /// A walled `size`x`size` warehouse with 10% walls and 40% boxes inside and 8 moves per
+    /// tile, in lines of 1000 moves.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let size = size.max(3);
+        let mut map = vec![vec!['#'; size]; size];
+        for row in &mut map[1..size - 1] {
+            for tile in &mut row[1..size - 1] {
+                *tile = if rng.chance(0.1) {
+                    '#'
+                } else if rng.chance(0.45) {
+                    'O'
+                } else {
+                    '.'
+                };
+            }
+        }
+        map[rng.range(1..=size - 2)][rng.range(1..=size - 2)] = '@';
+        let mut input: String = map
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+        input.push('\n');
+        let moves = ['^', '>', 'v', '<'];
+        for i in 0..size * size * 8 {
+            input.push(*rng.choose(&moves));
+            if i % 1000 == 999 {
+                input.push('\n');
+            }
+        }
+        input.push('\n');
+        input
+    }
+}
+
+pub fn next_wide(state: WideMapState, direction: &Direction) -> WideMapState {
+    if *direction == Direction::Left || *direction == Direction::Right {
+        next_wide_simple(state, direction)
+    } else {
+        next_wide_up_down(state, direction)
+    }
+}
+
+pub fn next_wide_up_down(mut state: WideMapState, direction: &Direction) -> WideMapState {
+    assert_eq!(
+        state.map[state.robot_position.row][state.robot_position.col],
+        WideObject::Robot
+    );
+
+    let new_robot_pos = step(state.robot_position, direction, 1);
+
+    let mut push_starts = vec![state.robot_position];
+    let mut all_pushes = Vec::new();
+    while let Some(push_start) = push_starts.pop() {
+        match get_push_target(&push_start, direction, &state.map) {
+            None => {
+                return state;
+            }
+            Some((push_length, new_push_starts)) => {
+                all_pushes.push((push_start, push_length));
+                push_starts.extend(new_push_starts);
+            }
+        }
+    }
+    let mut pushes_by_cols = vec![Vec::new(); state.map[0].len()];
+    for (push_start, push_length) in all_pushes {
+        pushes_by_cols[push_start.col].push((push_start, push_length));
+    }
+    for pushes in pushes_by_cols {
+        for (push_start, push_length) in deduplicate(pushes, direction) {
+            for i in (0..push_length).rev() {
+                let copy_from = step(push_start, direction, i);
+                let copy_to = step(push_start, direction, i + 1);
+                state.map[copy_to.row][copy_to.col] =
+                    state.map[copy_from.row][copy_from.col].clone();
+            }
+            state.map[push_start.row][push_start.col] = WideObject::Empty;
+        }
+    }
+    state.robot_position = new_robot_pos;
+    state
+}
+
+pub fn deduplicate(
+    mut pushes: Vec<(Coordinate, usize)>,
+    direction: &Direction,
+) -> Vec<(Coordinate, usize)> {
+    if *direction == Direction::Up {
+        pushes.sort_by_key(|a| a.0.row);
+        pushes.reverse();
+        // println!("pushes: {:?}", pushes);
+        for (push1, (push_2_index, push_2)) in pushes.iter().zip(pushes.iter().enumerate().skip(1))
+        {
+            if push1.0.row == push_2.0.row {
+                pushes.remove(push_2_index);
+                return deduplicate(pushes, direction);
+            }
+            if push1.0.row - push1.1 <= push_2.0.row {
+                pushes.remove(push_2_index);
+                return deduplicate(pushes, direction);
+            }
+        }
+        pushes.reverse();
+        pushes
+    } else {
+        // Down
+        pushes.sort_by_key(|a| a.0.row);
+        for (push1, (push_2_index, push_2)) in pushes.iter().zip(pushes.iter().enumerate().skip(1))
+        {
+            if push1.0.row == push_2.0.row {
+                pushes.remove(push_2_index);
+                return deduplicate(pushes, direction);
+            }
+            if push1.0.row + push1.1 >= push_2.0.row {
+                pushes.remove(push_2_index);
+                return deduplicate(pushes, direction);
+            }
+        }
+        pushes.reverse();
+        pushes
+    }
+}
+
+pub fn next_wide_simple(mut state: WideMapState, direction: &Direction) -> WideMapState {
+    let row = state.robot_position.row;
+    let col = state.robot_position.col;
+    assert_eq!(state.map[row][col], WideObject::Robot);
+
+    let new_robot_pos = step(state.robot_position, direction, 1);
+
+    if let Some((push_length, _)) = get_push_target(&state.robot_position, direction, &state.map) {
+        for i in (0..push_length).rev() {
+            let copy_from = step(state.robot_position, direction, i);
+            let copy_to = step(state.robot_position, direction, i + 1);
+            state.map[copy_to.row][copy_to.col] = state.map[copy_from.row][copy_from.col].clone();
+        }
+        state.map[row][col] = WideObject::Empty;
+        state.robot_position = new_robot_pos;
+        state
+    } else {
+        state
+    }
+}
+
+pub fn get_push_target(
+    push_start: &Coordinate,
+    direction: &Direction,
+    map: &[Vec<WideObject>],
+) -> Option<(usize, Vec<Coordinate>)> {
+    let mut push_length = 1;
+    let mut new_push_starts = Vec::new();
+    loop {
+        let push_pos = step(*push_start, direction, push_length);
+        match map[push_pos.row][push_pos.col] {
+            WideObject::Robot => {
+                panic!("This should not happen? Multiple robots on map?2")
+            }
+            WideObject::BoxLeft => {
+                new_push_starts.push(Coordinate {
+                    col: push_pos.col + 1,
+                    row: push_pos.row,
+                });
+            }
+            WideObject::BoxRight => {
+                new_push_starts.push(Coordinate {
+                    col: push_pos.col - 1,
+                    row: push_pos.row,
+                });
+            }
+            WideObject::Wall => {
+                return None;
+            }
+            WideObject::Empty => {
+                return Some((push_length, new_push_starts));
+            }
+        }
+        push_length += 1;
+    }
+}
+
+pub fn next(mut state: MapState, direction: &Direction) -> MapState {
+    let row = state.robot_position.row;
+    let col = state.robot_position.col;
+    assert_eq!(state.map[row][col], Object::Robot);
+
+    let new_robot_pos = step(state.robot_position, direction, 1);
+
+    let mut push_length = 1;
+    loop {
+        let push_pos = step(state.robot_position, direction, push_length);
+        match state.map[push_pos.row][push_pos.col] {
+            Object::Robot => {
+                panic!("This should not happen? Multiple robots on map?2")
+            }
+            Object::Box => { // Nothing..
+            }
+            Object::Wall => return state,
+            Object::Empty => {
+                state.robot_position = new_robot_pos;
+                state.map[row][col] = Object::Empty;
+                state.map[new_robot_pos.row][new_robot_pos.col] = Object::Robot;
+                if push_length > 1 {
+                    state.map[push_pos.row][push_pos.col] = Object::Box;
+                }
+                return state;
+            }
+        }
+        push_length += 1;
+    }
+}
+
+fn print_map(map: &[Vec<Object>]) {
+    let string = map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|o| format!("{o}"))
+                .collect::<Vec<String>>()
+                .join("")
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    log_debug!("{}", string);
+}
+
+fn print_wide_map(map: &[Vec<WideObject>]) {
+    let string = map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|o| format!("{o}"))
+                .collect::<Vec<String>>()
+                .join("")
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    log_debug!("{}", string);
+}
+
+#[derive(Debug, Clone)]
+pub struct MapState {
+    pub map: Vec<Vec<Object>>,
+    pub robot_position: Coordinate,
+}
+
+impl MapState {
+    pub(crate) fn widen(&self) -> WideMapState {
+        let mut wide_map = vec![Vec::new(); self.map.len()];
+        for (row, line) in self.map.iter().enumerate() {
+            for o in line {
+                match o {
+                    Object::Robot => {
+                        wide_map[row].push(WideObject::Robot);
+                        wide_map[row].push(WideObject::Empty);
+                    }
+                    Object::Box => {
+                        wide_map[row].push(WideObject::BoxLeft);
+                        wide_map[row].push(WideObject::BoxRight);
+                    }
+                    Object::Wall => {
+                        wide_map[row].push(WideObject::Wall);
+                        wide_map[row].push(WideObject::Wall);
+                    }
+                    Object::Empty => {
+                        wide_map[row].push(WideObject::Empty);
+                        wide_map[row].push(WideObject::Empty);
+                    }
+                }
+            }
+        }
+        let new_start = Coordinate {
+            row: self.robot_position.row,
+            col: self.robot_position.col * 2,
+        };
+        WideMapState {
+            map: wide_map,
+            robot_position: new_start,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WideMapState {
+    pub map: Vec<Vec<WideObject>>,
+    pub robot_position: Coordinate,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Object {
+    Robot,
+    Box,
+    Wall,
+    Empty,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum WideObject {
+    Robot,
+    BoxLeft,
+    BoxRight,
+    Wall,
+    Empty,
+}
+
+impl Display for Object {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Object::Robot => '@',
+                Object::Box => 'O',
+                Object::Wall => '#',
+                Object::Empty => '.',
+            },
+        )
+    }
+}
+
+impl Display for WideObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                WideObject::Robot => '@',
+                WideObject::BoxLeft => '[',
+                WideObject::BoxRight => ']',
+                WideObject::Wall => '#',
+                WideObject::Empty => '.',
+            },
+        )
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(MapState, Vec<Direction>), ParseError> {
+    let mut lines1: Vec<&str> = Vec::new();
+    let mut lines2: Vec<&str> = Vec::new();
+    let mut first = true;
+    for line in input.lines() {
+        if line.is_empty() {
+            first = false
+        } else if first {
+            lines1.push(line);
+        } else {
+            lines2.push(line);
+        }
+    }
+    Ok((parse_map(input, lines1)?, parse_movements(input, lines2)?))
+}
+
+pub fn parse_map(input: &str, lines: Vec<&str>) -> Result<MapState, ParseError> {
+    let rows = lines.len();
+    let cols = lines.first().map_or(0, |line| line.len());
+    let mut map = vec![vec![Object::Empty; cols]; rows];
+    let mut robot_position = None;
+    for (row, line) in lines.iter().enumerate() {
+        if line.len() != cols {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a row of {cols} tiles"),
+            ));
+        }
+        for (col, char) in line.char_indices() {
+            // The solvers rely on the walls around the map instead of checking bounds
+            let on_border = row == 0 || row == rows - 1 || col == 0 || col == cols - 1;
+            if on_border && char != '#' {
+                return Err(ParseError::at(
+                    input,
+                    &line[col..col + char.len_utf8()],
+                    "a wall `#` on the border of the map",
+                ));
+            }
+            if char == '#' {
+                map[row][col] = Object::Wall;
+            } else if char == '@' && robot_position.is_none() {
+                map[row][col] = Object::Robot;
+                robot_position = Some(Coordinate { col, row });
+            } else if char == 'O' {
+                map[row][col] = Object::Box;
+            } else if char != '.' {
+                return Err(ParseError::at(
+                    input,
+                    &line[col..col + char.len_utf8()],
+                    "`.`, `#`, `O` or a single `@`",
+                ));
+            }
+        }
+    }
+    let robot_position = robot_position
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "a robot `@` on the map"))?;
+    Ok(MapState {
+        map,
+        robot_position,
+    })
+}
+
+pub fn parse_movements(input: &str, lines: Vec<&str>) -> Result<Vec<Direction>, ParseError> {
+    let mut movements: Vec<Direction> = Vec::new();
+    for line in lines {
+        for (col, char) in line.char_indices() {
+            let direction = if char == '^' {
+                Direction::Up
+            } else if char == '>' {
+                Direction::Right
+            } else if char == '<' {
+                Direction::Left
+            } else if char == 'v' {
+                Direction::Down
+            } else {
+                return Err(ParseError::at(
+                    input,
+                    &line[col..col + char.len_utf8()],
+                    "a movement `^`, `>`, `<` or `v`",
+                ));
+            };
+            movements.push(direction);
+        }
+    }
+    Ok(movements)
+}
@@ -0,0 +1,18 @@
+use day_15::Day15;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day15 as Solver>::Parsed {
+    Day15.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day15.part1(&parsed()).to_string(), "10092");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day15.part2(&parsed()).to_string(), "9021");
+}
@@ -0,0 +1,350 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use tae_aoclib2025::{
+    log_debug, log_enabled, step, Answer, Coordinate, Direction, Level, ParseError, Rng, Solver,
+};
+
+pub struct Day16;
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct State {
+    pub coordinate: Coordinate,
+    pub direction: Direction,
+}
+
+impl Solver for Day16 {
+    type Parsed = Map;
+
+    fn day(&self) -> u32 {
+        16
+    }
+
+    fn name(&self) -> &'static str {
+        "Reindeer Maze"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(&self, map: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let (scores, _) = dijkstra(map, debug_print);
+
+        let end_states = [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
+        ]
+        .map(|direction| State {
+            coordinate: map.end,
+            direction,
+        });
+        let result1 = *end_states
+            .iter()
+            .map(|x| scores.get(x).unwrap())
+            .min()
+            .unwrap();
+        result1.into()
+    }
+
+    fn part2(&self, map: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let (_, predecessors) = dijkstra(map, false);
+
+        if debug_print {
+            print_one_predecessor_path(
+                State {
+                    coordinate: map.end,
+                    direction: Direction::Up,
+                },
+                map,
+                &predecessors,
+            );
+        }
+
+        let result2 = count_all_predecessor_paths(
+            State {
+                coordinate: map.end,
+                direction: Direction::Up,
+            },
+            map,
+            &predecessors,
+            debug_print,
+        );
+        result2.into()
+    }
+
+    /// A `size`x`size` maze (at least 5x5, made odd) with `S` in the bottom left and `E` in
+    /// the top right corner. Carved by a random depth-first search, with one extra opening per
+    /// 50 tiles so that there are several paths.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let size = size.max(5) | 1;
+        let mut maze = vec![vec!['#'; size]; size];
+        // Cells are the tiles at odd coordinates, the tiles between them are walls or passages
+        maze[1][1] = '.';
+        let mut stack: Vec<(usize, usize)> = vec![(1, 1)];
+        while let Some(&(row, col)) = stack.last() {
+            let unvisited: Vec<(usize, usize)> = [
+                (row.wrapping_sub(2), col),
+                (row + 2, col),
+                (row, col.wrapping_sub(2)),
+                (row, col + 2),
+            ]
+            .into_iter()
+            .filter(|&(row, col)| row < size - 1 && col < size - 1 && maze[row][col] == '#')
+            .collect();
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+            let (next_row, next_col) = *rng.choose(&unvisited);
+            maze[(row + next_row) / 2][(col + next_col) / 2] = '.';
+            maze[next_row][next_col] = '.';
+            stack.push((next_row, next_col));
+        }
+        for _ in 0..size * size / 50 {
+            maze[rng.range(1..=size - 2)][rng.range(1..=size - 2)] = '.';
+        }
+        maze[size - 2][1] = 'S';
+        maze[1][size - 2] = 'E';
+        maze.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+pub type Predecessors = HashMap<State, Vec<State>>;
+
+/// Lowest score of every reachable state and, for each state, all predecessors on a path with that score.
+pub fn dijkstra(map: &Map, debug_print: bool) -> (HashMap<State, usize>, Predecessors) {
+    let mut scores = HashMap::new();
+    let mut predecessors = HashMap::new();
+
+    let mut queue = BinaryHeap::new();
+    let start = State {
+        coordinate: map.start,
+        direction: Direction::Right,
+    };
+    queue.push(Reverse((0usize, start.clone())));
+    scores.insert(start, 0usize);
+    while let Some(Reverse((cur_score, cur))) = queue.pop() {
+        if debug_print {
+            log_debug!("{cur:?}: score {cur_score}");
+        }
+
+        let mut moves = Vec::new();
+
+        // Straight
+        let new_pos = step(cur.coordinate, &cur.direction, 1);
+        if !map.obstacles[new_pos.row][new_pos.col] {
+            let new_state = State {
+                coordinate: new_pos,
+                direction: cur.direction,
+            };
+            let new_score = cur_score + 1;
+            moves.push((new_state, new_score));
+        }
+
+        // Left
+        let new_dir = cur.direction.turn_left();
+        let new_state = State {
+            coordinate: cur.coordinate,
+            direction: new_dir,
+        };
+        let new_score = cur_score + 1000;
+        moves.push((new_state, new_score));
+
+        // Right
+        let new_dir = cur.direction.turn_right();
+        let new_state = State {
+            coordinate: cur.coordinate,
+            direction: new_dir,
+        };
+        let new_score = cur_score + 1000;
+        moves.push((new_state, new_score));
+
+        for (new_state, new_score) in moves.into_iter() {
+            if let Some(&score) = scores.get(&new_state) {
+                if new_score < score {
+                    queue.push(Reverse((new_score, new_state.clone())));
+                    scores.insert(new_state.clone(), new_score);
+                    predecessors.insert(new_state, vec![cur.clone()]);
+                } else if new_score == score {
+                    predecessors.get_mut(&new_state).unwrap().push(cur.clone());
+                }
+            } else {
+                queue.push(Reverse((new_score, new_state.clone())));
+                scores.insert(new_state.clone(), new_score);
+                predecessors.insert(new_state, vec![cur.clone()]);
+            }
+        }
+    }
+
+    (scores, predecessors)
+}
+
+fn print_one_predecessor_path(mut pos: State, map: &Map, predecessors: &Predecessors) {
+    let mut map_chars = init_char_map(map);
+
+    while let Some(new_pos) = predecessors.get(&pos) {
+        pos = new_pos.first().unwrap().clone();
+        map_chars[pos.coordinate.row][pos.coordinate.col] =
+            pos.direction.to_string().chars().next().unwrap();
+    }
+
+    print_char_map(&map_chars);
+}
+
+pub fn count_all_predecessor_paths(
+    pos: State,
+    map: &Map,
+    predecessors: &Predecessors,
+    debug_print: bool,
+) -> usize {
+    let mut visited_predecessors = HashSet::new();
+    let mut queue = vec![pos];
+    while let Some(pos) = queue.pop() {
+        if !visited_predecessors.contains(&pos) {
+            if let Some(new_positions) = predecessors.get(&pos) {
+                for new_pos in new_positions {
+                    if !visited_predecessors.contains(new_pos) {
+                        queue.push(new_pos.clone());
+                    }
+                }
+            }
+            visited_predecessors.insert(pos);
+        }
+    }
+
+    if debug_print {
+        let mut map_chars = init_char_map(map);
+        for state in &visited_predecessors {
+            map_chars[state.coordinate.row][state.coordinate.col] = 'O';
+        }
+        print_char_map(&map_chars);
+    }
+
+    let mut visited_coordinates = HashSet::new();
+    for x in visited_predecessors {
+        visited_coordinates.insert(x.coordinate);
+    }
+    visited_coordinates.len()
+}
+
+pub fn init_char_map(map: &Map) -> Vec<Vec<char>> {
+    map.obstacles
+        .iter()
+        .map(|x| {
+            x.iter()
+                .map(|x| if *x { '#' } else { '.' })
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>()
+}
+
+fn print_char_map(char_map: &[Vec<char>]) {
+    log_debug!(
+        "{}",
+        char_map
+            .iter()
+            .map(|x| x
+                .iter()
+                .map(|c| format!("{}", c))
+                .collect::<Vec<String>>()
+                .join(""))
+            .collect::<Vec<String>>()
+            .join("\n")
+    );
+}
+
+pub struct Map {
+    pub start: Coordinate,
+    pub end: Coordinate,
+    pub obstacles: Vec<Vec<bool>>,
+}
+
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let rows = lines.len();
+    let cols = lines.first().map_or(0, |line| line.len());
+    let mut obstacles = vec![vec![false; cols]; rows];
+    let mut start = None;
+    let mut end = None;
+    for (row, line) in lines.iter().enumerate() {
+        if line.len() != cols {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("a row of {cols} tiles"),
+            ));
+        }
+        for (col, char) in line.char_indices() {
+            // The solvers rely on the walls around the map instead of checking bounds
+            let on_border = row == 0 || row == rows - 1 || col == 0 || col == cols - 1;
+            if on_border && char != '#' {
+                return Err(ParseError::at(
+                    input,
+                    &line[col..col + char.len_utf8()],
+                    "a wall `#` on the border of the map",
+                ));
+            }
+            if char == '#' {
+                obstacles[row][col] = true;
+            } else if char == 'S' && start.is_none() {
+                start = Some(Coordinate { col, row });
+            } else if char == 'E' && end.is_none() {
+                end = Some(Coordinate { col, row });
+            } else if char != '.' {
+                return Err(ParseError::at(
+                    input,
+                    &line[col..col + char.len_utf8()],
+                    "`.`, `#` or a single `S` and `E`",
+                ));
+            }
+        }
+    }
+    let end_of_input = &input[input.len()..];
+    let start = start.ok_or_else(|| ParseError::at(input, end_of_input, "a start tile `S`"))?;
+    let end = end.ok_or_else(|| ParseError::at(input, end_of_input, "an end tile `E`"))?;
+    if !is_reachable(&obstacles, start, end) {
+        let line = lines[end.row];
+        return Err(ParseError::at(
+            input,
+            &line[end.col..end.col + 1],
+            "an end tile reachable from `S`",
+        ));
+    }
+    Ok(Map {
+        obstacles,
+        start,
+        end,
+    })
+}
+
+/// Whether `end` can be reached from `start`, walls being `true` in `obstacles`. The map must
+/// be enclosed by walls.
+pub fn is_reachable(obstacles: &[Vec<bool>], start: Coordinate, end: Coordinate) -> bool {
+    let mut visited = vec![vec![false; obstacles[0].len()]; obstacles.len()];
+    visited[start.row][start.col] = true;
+    let mut stack = vec![start];
+    while let Some(coordinate) = stack.pop() {
+        if coordinate == end {
+            return true;
+        }
+        for direction in [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ] {
+            let next = step(coordinate, &direction, 1);
+            if !obstacles[next.row][next.col] && !visited[next.row][next.col] {
+                visited[next.row][next.col] = true;
+                stack.push(next);
+            }
+        }
+    }
+    false
+}
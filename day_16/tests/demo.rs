@@ -0,0 +1,24 @@
+use day_16::Day16;
+use tae_aoclib2025::Solver;
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day16 as Solver>::Parsed {
+    Day16.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day16.part1(&parsed()).to_string(), "7036");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day16.part2(&parsed()).to_string(), "45");
+}
+
+#[test]
+fn unreachable_end_is_rejected() {
+    let error = Day16.parse("#####\n#S#E#\n#####\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 4));
+}
@@ -0,0 +1,488 @@
+use std::cmp::PartialEq;
+use std::collections::HashSet;
+use std::fmt::Display;
+use tae_aoclib2025::{
+    expect_next, expect_prefix, log_debug, log_enabled, parse_token, Answer, Level, ParseError,
+    Rng, Solver,
+};
+
+pub struct Day17;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MachineState {
+    pub instruction_pointer: usize,
+    pub register_a: usize,
+    pub register_b: usize,
+    pub register_c: usize,
+}
+impl MachineState {
+    pub fn with_a_value(a_register: usize) -> Self {
+        Self {
+            instruction_pointer: 0,
+            register_a: a_register,
+            register_b: 0,
+            register_c: 0,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: Operand,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Opcode {
+    ADV, // A = A / (1 << Combo)
+    BXL, // B = B ^ Literal
+    BST, // B = Combo % 8
+    JNZ, // if A != 0 { goto Literal; }
+    BXC, // B = B ^ C
+    OUT, // print(Combo % 8)
+    BDV, // B = A / (1 << Combo)
+    CDV, // C = A / (1 << Combo)
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Operand {
+    //Literal
+    Literal0,
+    Literal1,
+    Literal2,
+    Literal3,
+    Literal4,
+    Literal5,
+    Literal6,
+    Literal7,
+    // Combo
+    RegisterA,
+    RegisterB,
+    RegisterC,
+    Reserved,
+}
+
+impl Solver for Day17 {
+    type Parsed = (Vec<Instruction>, Vec<usize>, MachineState);
+
+    fn day(&self) -> u32 {
+        17
+    }
+
+    fn name(&self) -> &'static str {
+        "Chronospatial Computer"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (program, _, state): &Self::Parsed) -> Answer {
+        // Programs that never halt do not parse
+        run(program, state.clone(), false).unwrap().into()
+    }
+
+    fn part2(&self, (program, program_binary, _): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        if debug_print {
+            log_debug!(
+                "Full program:\n{}",
+                program
+                    .iter()
+                    .map(|x| format!("{x}"))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+        }
+
+        // Programs that cannot output themselves, like the puzzle's part 1 example, have no part 2
+        match search_a_register(program, program_binary, debug_print) {
+            Some(register_a) => register_a.into(),
+            None => Answer::None,
+        }
+    }
+
+    /// A program like the puzzle's, printing three bits of register A per loop, that can output
+    /// itself as part 2 requires. Register A starts with `size` random bits (3 to 63).
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        let bits = size.clamp(3, 63);
+        let register_a = rng.next_u64() >> (64 - bits);
+        loop {
+            // bst A, bxl, cdv B, then bxl and bxc in any order, out B and adv 3 in any order, jnz 0
+            let mut mixing = [[1, rng.below(8)], [4, rng.below(7)]];
+            rng.shuffle(&mut mixing);
+            let mut output_and_shift = [[5, 5], [0, 3]];
+            rng.shuffle(&mut output_and_shift);
+            let binary_program: Vec<usize> = [[2, 4], [1, rng.below(8)], [7, 5]]
+                .into_iter()
+                .chain(mixing)
+                .chain(output_and_shift)
+                .chain([[3, 0]])
+                .flatten()
+                .collect();
+            let program: Vec<String> = binary_program.iter().map(|x| x.to_string()).collect();
+            let input = format!(
+                "Register A: {register_a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                program.join(",")
+            );
+            let (program, binary_program, _) = parse_input(&input).unwrap();
+            if search_a_register(&program, &binary_program, false).is_some() {
+                return input;
+            }
+        }
+    }
+}
+
+pub fn search_a_register(
+    program: &[Instruction],
+    target_output: &[usize],
+    debug_print: bool,
+) -> Option<usize> {
+    let mut partial_target = vec![target_output[0]];
+    let mut filtered_values = Vec::new();
+    let mut lowest_n_bits = 10;
+    for a_value in 0..(1 << lowest_n_bits) {
+        let state = MachineState::with_a_value(a_value);
+        if simulate_against_partial_target(program, &partial_target, state) {
+            filtered_values.push(a_value);
+        }
+    }
+    while partial_target.len() < target_output.len() {
+        partial_target.push(target_output[partial_target.len()]);
+        let mut next_filtered_values = Vec::new();
+        for a_value_0_n in filtered_values {
+            for a_value_n_n_plus_3 in 0..(1 << 3) {
+                let a_value = (a_value_n_n_plus_3 << lowest_n_bits) + a_value_0_n;
+                let state = MachineState::with_a_value(a_value);
+                if simulate_against_partial_target(program, &partial_target, state) {
+                    next_filtered_values.push(a_value);
+                }
+            }
+        }
+        lowest_n_bits += 3;
+        filtered_values = next_filtered_values;
+        if debug_print {
+            log_debug!("Possibilities for the lowest {lowest_n_bits} bits to hit the first {} targets (count: {})", partial_target.len(), filtered_values.len());
+            if filtered_values.len() < 10 {
+                log_debug!("{:?}", filtered_values);
+            } else {
+                log_debug!(
+                    "{}, {}, {}, ...",
+                    filtered_values[0],
+                    filtered_values[1],
+                    filtered_values[2]
+                );
+            }
+        }
+    }
+    filtered_values
+        .iter()
+        .filter(|candidate_a_value| {
+            run(
+                program,
+                MachineState::with_a_value(**candidate_a_value),
+                false,
+            )
+            .is_some_and(|output| output == target_output)
+        })
+        .min()
+        .copied()
+}
+
+/// Whether the program outputs `target_output` first. The remaining output is not checked.
+pub fn simulate_against_partial_target(
+    program: &[Instruction],
+    target_output: &[usize],
+    mut state: MachineState,
+) -> bool {
+    let program_size = program.len();
+    let mut target_iter = target_output.iter();
+    let mut loop_detector = LoopDetector::default();
+    while state.instruction_pointer < program_size {
+        let instruction = &program[state.instruction_pointer];
+        if instruction.opcode == Opcode::JNZ && loop_detector.is_looping(&state) {
+            return false;
+        }
+        if let Some(out) = execute(&mut state, instruction) {
+            if let Some(target) = target_iter.next() {
+                if out != *target {
+                    return false;
+                }
+            }
+            if target_iter.len() == 0 {
+                return true;
+            }
+        }
+    }
+    target_iter.next().is_none()
+}
+
+/// The output of the program, `None` if it never halts.
+pub fn run(
+    program: &[Instruction],
+    mut state: MachineState,
+    debug_print: bool,
+) -> Option<Vec<usize>> {
+    let program_size = program.len();
+    let mut output = Vec::new();
+    let mut loop_detector = LoopDetector::default();
+    while state.instruction_pointer < program_size {
+        let instruction = &program[state.instruction_pointer];
+        if instruction.opcode == Opcode::JNZ && loop_detector.is_looping(&state) {
+            return None;
+        }
+        if debug_print {
+            log_debug!("{state}");
+            log_debug!("{instruction}");
+        }
+        if let Some(out) = execute(&mut state, instruction) {
+            output.push(out);
+        }
+    }
+    Some(output)
+}
+
+/// Detects a machine looping forever: being deterministic, it does once it is in the same state
+/// at a jump twice, as every loop passes one. States are only recorded after the first jumps, so
+/// that programs like the puzzle's, halting after a loop per 3 bits of register A, skip the
+/// bookkeeping.
+#[derive(Default)]
+pub struct LoopDetector {
+    jumps: usize,
+    jumped_from: Option<HashSet<MachineState>>,
+}
+
+impl LoopDetector {
+    const UNRECORDED_JUMPS: usize = 64;
+
+    /// Whether the machine, about to execute a jump, loops forever.
+    pub fn is_looping(&mut self, state: &MachineState) -> bool {
+        self.jumps += 1;
+        self.jumps > Self::UNRECORDED_JUMPS && self.is_repeating(state)
+    }
+
+    #[cold]
+    fn is_repeating(&mut self, state: &MachineState) -> bool {
+        !self
+            .jumped_from
+            .get_or_insert_with(HashSet::new)
+            .insert(state.clone())
+    }
+}
+
+// ADV, // A = A / (1 << Combo)
+// BXL, // B = B ^ Literal
+// BST, // B = Combo % 8
+// JNZ, // if A != 0 { goto Literal; }
+// BXC, // B = B ^ C
+// OUT, // print(Combo % 8)
+// BDV, // B = A / (1 << Combo)
+// CDV, // C = A / (1 << Combo)
+
+pub fn execute(state: &mut MachineState, instruction: &Instruction) -> Option<usize> {
+    state.instruction_pointer += 1;
+    let operand_value = get_value(state, &instruction.operand);
+    match instruction.opcode {
+        Opcode::ADV => {
+            state.register_a >>= operand_value;
+        }
+        Opcode::BXL => {
+            state.register_b ^= operand_value;
+        }
+        Opcode::BST => {
+            state.register_b = operand_value % 8;
+        }
+        Opcode::JNZ => {
+            if state.register_a != 0 {
+                state.instruction_pointer = operand_value;
+            }
+        }
+        Opcode::BXC => {
+            state.register_b ^= state.register_c;
+        }
+        Opcode::OUT => return Some(operand_value % 8),
+        Opcode::BDV => {
+            state.register_b = state.register_a >> operand_value;
+        }
+        Opcode::CDV => {
+            state.register_c = state.register_a >> operand_value;
+        }
+    }
+    None
+}
+
+pub fn get_value(state: &MachineState, operand: &Operand) -> usize {
+    match operand {
+        Operand::Literal0 => 0,
+        Operand::Literal1 => 1,
+        Operand::Literal2 => 2,
+        Operand::Literal3 => 3,
+        Operand::Literal4 => 4,
+        Operand::Literal5 => 5,
+        Operand::Literal6 => 6,
+        Operand::Literal7 => 7,
+        Operand::RegisterA => state.register_a,
+        Operand::RegisterB => state.register_b,
+        Operand::RegisterC => state.register_c,
+        Operand::Reserved => {
+            panic!("Should not appear in programs..")
+        }
+    }
+}
+
+pub fn parse_input(
+    input: &str,
+) -> Result<(Vec<Instruction>, Vec<usize>, MachineState), ParseError> {
+    let mut lines = input.lines();
+    let mut register = |name: &str| -> Result<usize, ParseError> {
+        let line = expect_next(input, input, &mut lines, &format!("`Register {name}: `"))?;
+        let value = expect_prefix(input, line, &format!("Register {name}: "))?;
+        parse_token(input, value, "a number")
+    };
+    let register_a = register("A")?;
+    let register_b = register("B")?;
+    let register_c = register("C")?;
+
+    let separator = expect_next(input, input, &mut lines, "an empty line")?;
+    if !separator.is_empty() {
+        return Err(ParseError::at(input, separator, "an empty line"));
+    }
+
+    let program_line = expect_next(input, input, &mut lines, "`Program: `")?;
+    let binary_program = expect_prefix(input, program_line, "Program: ")?
+        .split(",")
+        .map(|s| {
+            let value = parse_token(input, s, "a 3-bit number")?;
+            if value > 7 {
+                return Err(ParseError::at(input, s, "a 3-bit number"));
+            }
+            Ok(value)
+        })
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+    if binary_program.len() % 2 != 0 {
+        return Err(ParseError::at(
+            input,
+            &program_line[program_line.len()..],
+            "an operand",
+        ));
+    }
+
+    let mut program = Vec::new();
+    for pair in binary_program.chunks(2) {
+        let opcode = parse_opcode(pair[0]);
+        let operand = if takes_literal(opcode) {
+            parse_literal(pair[1])
+        } else {
+            parse_combo(pair[1])
+        };
+        let instruction = Instruction { opcode, operand };
+        program.push(instruction);
+    }
+
+    let state = MachineState {
+        register_a,
+        register_b,
+        register_c,
+        instruction_pointer: 0,
+    };
+    if run(&program, state.clone(), false).is_none() {
+        return Err(ParseError::at(input, program_line, "a program that halts"));
+    }
+    Ok((program, binary_program, state))
+}
+
+pub fn parse_opcode(opcode: usize) -> Opcode {
+    match opcode {
+        0 => Opcode::ADV,
+        1 => Opcode::BXL,
+        2 => Opcode::BST,
+        3 => Opcode::JNZ,
+        4 => Opcode::BXC,
+        5 => Opcode::OUT,
+        6 => Opcode::BDV,
+        7 => Opcode::CDV,
+        _ => {
+            panic!("Unknown opcode: {}", opcode);
+        }
+    }
+}
+
+pub fn parse_literal(operand: usize) -> Operand {
+    match operand {
+        0 => Operand::Literal0,
+        1 => Operand::Literal1,
+        2 => Operand::Literal2,
+        3 => Operand::Literal3,
+        4 => Operand::Literal4,
+        5 => Operand::Literal5,
+        6 => Operand::Literal6,
+        7 => Operand::Literal7,
+        _ => {
+            panic!("Unknown literal operand: {}", operand);
+        }
+    }
+}
+
+pub fn parse_combo(operand: usize) -> Operand {
+    match operand {
+        0 => Operand::Literal0,
+        1 => Operand::Literal1,
+        2 => Operand::Literal2,
+        3 => Operand::Literal3,
+        4 => Operand::RegisterA,
+        5 => Operand::RegisterB,
+        6 => Operand::RegisterC,
+        7 => Operand::Reserved,
+        _ => {
+            panic!("Unknown combo operand: {}", operand);
+        }
+    }
+}
+
+pub fn takes_literal(opcode: Opcode) -> bool {
+    opcode == Opcode::BXL || opcode == Opcode::JNZ
+}
+
+impl Display for MachineState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "A: {:4} B: {:4} C: {:4}",
+            self.register_a, self.register_b, self.register_c
+        )
+    }
+}
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.opcode {
+            Opcode::ADV => write!(f, "A = A >> {}", self.operand),
+            Opcode::BXL => write!(f, "B = B ^ {}", self.operand),
+            Opcode::BST => write!(f, "B = {} % 8", self.operand),
+            Opcode::JNZ => write!(f, "if A != 0 {{ goto {}; }}", self.operand),
+            Opcode::BXC => write!(f, "B = B ^ C"),
+            Opcode::OUT => write!(f, "print({} % 8)", self.operand),
+            Opcode::BDV => write!(f, "B = A >> {}", self.operand),
+            Opcode::CDV => write!(f, "C = A >> {}", self.operand),
+        }
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Literal0 => write!(f, "0"),
+            Operand::Literal1 => write!(f, "1"),
+            Operand::Literal2 => write!(f, "2"),
+            Operand::Literal3 => write!(f, "3"),
+            Operand::Literal4 => write!(f, "4"),
+            Operand::Literal5 => write!(f, "5"),
+            Operand::Literal6 => write!(f, "6"),
+            Operand::Literal7 => write!(f, "7"),
+            Operand::RegisterA => write!(f, "A"),
+            Operand::RegisterB => write!(f, "B"),
+            Operand::RegisterC => write!(f, "C"),
+            Operand::Reserved => write!(f, "XXX"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use day_17::Day17;
+use tae_aoclib2025::{Answer, Solver};
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day17 as Solver>::Parsed {
+    Day17.parse(DEMO).unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day17.part1(&parsed()).to_string(), "5,7,3,0");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day17.part2(&parsed()).to_string(), "117440");
+}
+
+#[test]
+fn no_part2_for_program_not_outputting_itself() {
+    let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+    let parsed = Day17.parse(input).unwrap();
+    assert_eq!(Day17.part1(&parsed).to_string(), "4,6,3,5,6,3,5,2,1,0");
+    assert_eq!(Day17.part2(&parsed), Answer::None);
+}
+
+#[test]
+fn program_looping_forever_is_rejected() {
+    let input = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 6,3,5,4,3,0\n";
+    let error = Day17.parse(input).err().unwrap();
+    assert_eq!((error.line, error.column), (5, 1));
+}
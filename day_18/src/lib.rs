@@ -0,0 +1,351 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use tae_aoclib2025::{
+    expect_split_once, log_debug, log_enabled, parse_token, Answer, Coordinate, Level, Params,
+    ParseError, Rng, Solver,
+};
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    /// Falling bytes, size of the memory space and the number of bytes fallen in part 1.
+    type Parsed = (Vec<Coordinate>, usize, usize);
+
+    fn day(&self) -> u32 {
+        18
+    }
+
+    fn name(&self) -> &'static str {
+        "RAM Run"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        self.parse_with_params(input, &Params::default())
+    }
+
+    fn parse_with_params(&self, input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        parse_input(input, params)
+    }
+
+    fn part1(&self, (bytes, dim, first_n_bytes): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let (dim, first_n_bytes) = (*dim, *first_n_bytes);
+
+        let mut board = vec![vec![false; dim]; dim];
+
+        for byte in &bytes[..first_n_bytes] {
+            board[byte.row][byte.col] = true;
+        }
+
+        if debug_print {
+            print_board(&board);
+        }
+
+        let (start, goal) = start_and_goal(dim);
+        let result1 = djikstra(start, goal, &board, debug_print).unwrap();
+        result1.into()
+    }
+
+    fn part2(&self, (bytes, dim, _): &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let dim = *dim;
+        let (start, goal) = start_and_goal(dim);
+
+        if debug_print {
+            log_debug!("Part 2")
+        }
+        let mut board = vec![vec![false; dim]; dim];
+        let bytes = deduplicate(bytes.clone(), dim);
+
+        let result2: Option<Coordinate> = bin_search(
+            &mut board,
+            &bytes,
+            0,
+            bytes.len() - 1,
+            start,
+            goal,
+            debug_print,
+        );
+        result2.unwrap().into()
+    }
+
+    /// Bytes falling into the 71x71 memory space until they cut off the exit, `size` of them
+    /// or more if needed. The first 1024 bytes, fallen in part 1, always leave a path.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        // The defaults of `dimensions` for inputs without params
+        let (dim, first_n_bytes) = (71, 1024);
+        let (start, goal) = start_and_goal(dim);
+        loop {
+            let mut bytes: Vec<Coordinate> = (0..dim * dim)
+                .map(|i| Coordinate {
+                    col: i % dim,
+                    row: i / dim,
+                })
+                .filter(|byte| *byte != start && *byte != goal)
+                .collect();
+            rng.shuffle(&mut bytes);
+            let mut board = vec![vec![false; dim]; dim];
+            for byte in &bytes[..first_n_bytes] {
+                board[byte.row][byte.col] = true;
+            }
+            if djikstra(start, goal, &board, false).is_none() {
+                continue;
+            }
+            let mut board = vec![vec![false; dim]; dim];
+            let last_byte =
+                bin_search(&mut board, &bytes, 0, bytes.len() - 1, start, goal, false).unwrap();
+            let cut_off = bytes.iter().position(|byte| *byte == last_byte).unwrap();
+            return bytes[..size.clamp(cut_off + 1, bytes.len())]
+                .iter()
+                .map(|byte| format!("{},{}\n", byte.col, byte.row))
+                .collect();
+        }
+    }
+}
+
+/// The memory space is 71x71 with 1024 fallen bytes for real inputs and 7x7 with 12 for the
+/// example, unless given with the `size` and `fallen_bytes` params.
+pub fn dimensions(bytes: &[Coordinate], params: &Params) -> Result<(usize, usize), ParseError> {
+    let default_dim = if bytes.iter().map(|x| x.col).max().unwrap() > 6 {
+        71
+    } else {
+        7
+    };
+    let dim = params.get("size", "a size")?.unwrap_or(default_dim);
+    let default_first_n_bytes = if dim > 7 { 1024 } else { 12 };
+    let first_n_bytes = params
+        .get("fallen_bytes", "a number of bytes")?
+        .unwrap_or(default_first_n_bytes);
+    Ok((dim, first_n_bytes))
+}
+
+pub fn start_and_goal(dim: usize) -> (Coordinate, Coordinate) {
+    let start = Coordinate { col: 0, row: 0 };
+    let goal = Coordinate {
+        col: dim - 1,
+        row: dim - 1,
+    };
+    (start, goal)
+}
+
+pub fn deduplicate(bytes: Vec<Coordinate>, dim: usize) -> Vec<Coordinate> {
+    let mut visited = vec![vec![false; dim]; dim];
+    let mut result = Vec::new();
+    for byte in bytes {
+        if visited[byte.row][byte.col] {
+            continue;
+        }
+        visited[byte.row][byte.col] = true;
+        result.push(byte);
+    }
+    result
+}
+
+pub fn bin_search(
+    board: &mut [Vec<bool>],
+    bytes: &[Coordinate],
+    lower: usize,
+    upper: usize,
+    start: Coordinate,
+    goal: Coordinate,
+    debug_print: bool,
+) -> Option<Coordinate> {
+    let middle = (lower + upper) / 2;
+    if lower == upper {
+        if debug_print {
+            log_debug!("Found result at index {middle}")
+        }
+        return Some(bytes[middle]);
+    }
+    for byte in &bytes[lower..=middle] {
+        board[byte.row][byte.col] = true;
+    }
+    if debug_print {
+        log_debug!("Board after {middle} steps. Current range is [{lower},{upper}]");
+        print_board(board);
+        log_debug!("Performing djikstra search");
+    }
+    let result = djikstra(start, goal, board, debug_print);
+    if result.is_none() {
+        for byte in &bytes[lower..=middle] {
+            board[byte.row][byte.col] = false;
+        }
+        bin_search(board, bytes, lower, middle, start, goal, debug_print)
+    } else {
+        bin_search(board, bytes, middle + 1, upper, start, goal, debug_print)
+    }
+}
+
+pub fn djikstra(
+    start: Coordinate,
+    goal: Coordinate,
+    board: &[Vec<bool>],
+    debug_print: bool,
+) -> Option<usize> {
+    let dim = board.len();
+    let mut queue: BinaryHeap<(Reverse<usize>, Coordinate)> = BinaryHeap::new();
+    let mut visited = vec![vec![false; dim]; dim];
+    let mut scores = vec![vec![None; dim]; dim];
+    queue.push((Reverse(0), start));
+    scores[start.row][start.col] = Some(0);
+
+    while let Some((Reverse(score), cur)) = queue.pop() {
+        if visited[cur.row][cur.col] {
+            continue;
+        }
+        assert_eq!(scores[cur.row][cur.col].unwrap(), score);
+        if cur == goal {
+            if debug_print {
+                print_scores(&scores, board);
+            }
+            return Some(score);
+        }
+        for neighbor in neighbors(cur, dim, dim) {
+            if !board[neighbor.row][neighbor.col] && !visited[neighbor.row][neighbor.col] {
+                let neighbor_score = score + 1;
+                if scores[neighbor.row][neighbor.col].is_none()
+                    || neighbor_score < scores[neighbor.row][neighbor.col].unwrap()
+                {
+                    scores[neighbor.row][neighbor.col] = Some(neighbor_score);
+                    queue.push((Reverse(neighbor_score), neighbor));
+                }
+            }
+        }
+        visited[cur.row][cur.col] = true;
+    }
+    if debug_print {
+        print_scores(&scores, board);
+    }
+    assert_eq!(scores[goal.row][goal.col], None);
+    None
+}
+
+pub fn neighbors(pos: Coordinate, cols: usize, rows: usize) -> Vec<Coordinate> {
+    let mut result = Vec::new();
+    if pos.row > 0 {
+        result.push(Coordinate {
+            col: pos.col,
+            row: pos.row - 1,
+        });
+    }
+    if pos.row < rows - 1 {
+        result.push(Coordinate {
+            col: pos.col,
+            row: pos.row + 1,
+        });
+    }
+    if pos.col > 0 {
+        result.push(Coordinate {
+            col: pos.col - 1,
+            row: pos.row,
+        });
+    }
+    if pos.col < cols - 1 {
+        result.push(Coordinate {
+            col: pos.col + 1,
+            row: pos.row,
+        });
+    }
+    result
+}
+
+fn print_board(board: &[Vec<bool>]) {
+    log_debug!(
+        "{}",
+        board
+            .iter()
+            .map(|row| row
+                .iter()
+                .map(|x| if *x { "#" } else { "." })
+                .collect::<Vec<&str>>()
+                .join(""))
+            .collect::<Vec<String>>()
+            .join("\n")
+    )
+}
+
+fn print_scores(scores: &[Vec<Option<usize>>], board: &[Vec<bool>]) {
+    let mut output = vec![Vec::new(); board.len()];
+    for (row, line) in scores.iter().enumerate() {
+        for (col, score) in line.iter().enumerate() {
+            if let Some(score) = score {
+                output[row].push(format!("{}", score % 10));
+            } else if board[row][col] {
+                output[row].push("#".to_string());
+            } else {
+                output[row].push(".".to_string());
+            }
+        }
+    }
+    log_debug!(
+        "{}",
+        output
+            .iter()
+            .map(|row| row.join(""))
+            .collect::<Vec<String>>()
+            .join("\n")
+    )
+}
+
+pub fn parse_input(
+    input: &str,
+    params: &Params,
+) -> Result<(Vec<Coordinate>, usize, usize), ParseError> {
+    let bytes = input
+        .lines()
+        .map(|line| {
+            let (col, row) = expect_split_once(input, line, ",")?;
+            let coordinate = Coordinate {
+                col: parse_token(input, col, "a column")?,
+                row: parse_token(input, row, "a row")?,
+            };
+            Ok((line, coordinate))
+        })
+        .collect::<Result<Vec<(&str, Coordinate)>, ParseError>>()?;
+    if bytes.is_empty() {
+        return Err(ParseError::at(input, input, "at least one byte position"));
+    }
+    let coordinates: Vec<Coordinate> = bytes.iter().map(|(_, coordinate)| *coordinate).collect();
+    let (dim, first_n_bytes) = dimensions(&coordinates, params)?;
+    if let Some((line, _)) = bytes
+        .iter()
+        .find(|(_, coordinate)| coordinate.col >= dim || coordinate.row >= dim)
+    {
+        return Err(ParseError::at(
+            input,
+            line,
+            format!("a position within {dim}x{dim}"),
+        ));
+    }
+    if first_n_bytes >= coordinates.len() {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            format!("more than {first_n_bytes} byte positions"),
+        ));
+    }
+    let (start, goal) = start_and_goal(dim);
+    let mut board = vec![vec![false; dim]; dim];
+    for byte in &coordinates[..first_n_bytes] {
+        board[byte.row][byte.col] = true;
+    }
+    if djikstra(start, goal, &board, false).is_none() {
+        let (line, _) = bytes[first_n_bytes - 1];
+        return Err(ParseError::at(
+            input,
+            line,
+            format!("the exit still reachable after the first {first_n_bytes} bytes"),
+        ));
+    }
+    for byte in &coordinates[first_n_bytes..] {
+        board[byte.row][byte.col] = true;
+    }
+    if djikstra(start, goal, &board, false).is_some() {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "more bytes, until they cut off the exit",
+        ));
+    }
+    Ok((coordinates, dim, first_n_bytes))
+}
@@ -0,0 +1,43 @@
+use day_18::Day18;
+use tae_aoclib2025::{Params, Solver};
+
+const DEMO: &str = include_str!("../input/demo.txt");
+
+fn parsed() -> <Day18 as Solver>::Parsed {
+    Day18
+        .parse_with_params(
+            DEMO,
+            &Params::parse(include_str!("../input/demo.params")).unwrap(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn part1_on_demo() {
+    assert_eq!(Day18.part1(&parsed()).to_string(), "22");
+}
+
+#[test]
+fn part2_on_demo() {
+    assert_eq!(Day18.part2(&parsed()).to_string(), "6,1");
+}
+
+#[test]
+fn exit_cut_off_in_part1_is_rejected() {
+    let params = Params::parse("size=3\nfallen_bytes=1\n").unwrap();
+    let error = Day18
+        .parse_with_params("2,2\n1,1\n", &params)
+        .err()
+        .unwrap();
+    assert_eq!((error.line, error.column), (1, 1));
+}
+
+#[test]
+fn exit_never_cut_off_is_rejected() {
+    let params = Params::parse("size=3\nfallen_bytes=1\n").unwrap();
+    let error = Day18
+        .parse_with_params("1,1\n0,1\n", &params)
+        .err()
+        .unwrap();
+    assert_eq!((error.line, error.column), (3, 1));
+}
@@ -0,0 +1,41 @@
+use tae_aoclib2025::{log_debug, log_enabled, Answer, Level, ParseError, Rng, Solver};
+
+pub struct DayXX;
+
+impl Solver for DayXX {
+    type Parsed = Vec<String>;
+
+    fn day(&self) -> u32 {
+        XX
+    }
+
+    fn name(&self) -> &'static str {
+        "Day XX"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(&self, lines: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let result1 = 0;
+        if debug_print {
+            log_debug!("{} lines", lines.len());
+        }
+        result1.into()
+    }
+
+    fn part2(&self, lines: &Self::Parsed) -> Answer {
+        let debug_print = log_enabled!(Level::Debug);
+        let result2 = 0;
+        if debug_print {
+            log_debug!("{} lines", lines.len());
+        }
+        result2.into()
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        (0..size).map(|_| format!("{}\n", rng.below(100))).collect()
+    }
+}
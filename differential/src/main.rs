@@ -0,0 +1,149 @@
+//! Compares the reference and fast implementations registered by the days, see
+//! `Solver::differentials`: `cargo run --release --bin differential -- 7 --inputs 10000`.
+//!
+//! Both implementations run on the day's fixtures first, then on generated inputs of growing
+//! size. The first input they disagree on is reported per day, generated ones with the
+//! `generate` command reproducing them, so that optimizations can be validated automatically.
+
+use aoc::registry;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tae_aoclib2025::{
+    display_path, find_disagreement, get_files_from_dir, normalize_input, workspace_root,
+    Disagreement, DynSolver, Params,
+};
+
+const USAGE: &str = "Usage: differential [<day> | <first>..<last> | all] [options]
+  --inputs <n>     generated inputs per day (default 1000)
+  --max-size <n>   largest size passed to the generator (default 20)
+  --seed <n>       seed of the first generated input, printed at the start to reproduce a run";
+
+struct DifferentialOptions {
+    inputs: usize,
+    max_size: usize,
+    seed: u64,
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!("{USAGE}");
+    std::process::exit(2);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).peekable();
+    let selection = match args.next_if(|arg| !arg.starts_with('-')) {
+        Some(selection) => selection,
+        None => "all".to_string(),
+    };
+    let mut options = DifferentialOptions {
+        inputs: 1000,
+        max_size: 20,
+        seed: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64,
+    };
+    while let Some(arg) = args.next() {
+        let Some(value) = args.next() else {
+            usage_error(&format!("Missing value for {arg}"));
+        };
+        match arg.as_str() {
+            "--inputs" => options.inputs = parse_value(&arg, &value),
+            "--max-size" => options.max_size = parse_value(&arg, &value),
+            "--seed" => options.seed = parse_value(&arg, &value),
+            _ => usage_error(&format!("Unknown option: {arg}")),
+        }
+    }
+    if options.max_size == 0 {
+        usage_error("The largest size must be at least 1");
+    }
+    let registry = registry();
+    let solvers: Vec<&dyn DynSolver> = registry
+        .select(&selection)
+        .unwrap_or_else(|message| usage_error(&message))
+        .into_iter()
+        .filter(|solver| !solver.differential_names().is_empty())
+        .collect();
+    if solvers.is_empty() {
+        println!("No differentials registered for the selected days");
+        return;
+    }
+
+    println!("seed {}", options.seed);
+    let mut disagreeing_days = 0;
+    for solver in solvers {
+        if !check_day(solver, &options) {
+            disagreeing_days += 1;
+        }
+    }
+    if disagreeing_days > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| usage_error(&format!("Invalid value for {option}: {value}")))
+}
+
+/// Returns whether the implementations agree on all inputs.
+fn check_day(solver: &dyn DynSolver, options: &DifferentialOptions) -> bool {
+    let input_dir = workspace_root().join(solver.label()).join("input");
+    for (contents, path) in get_files_from_dir(&input_dir) {
+        let Ok(contents) = contents else {
+            continue;
+        };
+        if contents.is_empty() {
+            continue;
+        }
+        let params = Params::for_input(&path).unwrap_or_else(|error| {
+            eprintln!("{error}");
+            std::process::exit(1);
+        });
+        let (input, _) = normalize_input(&contents, solver.trim_policy());
+        match solver.check_differentials(&input, &params) {
+            Ok(disagreements) if disagreements.is_empty() => {}
+            Ok(disagreements) => {
+                report(solver, &disagreements);
+                println!("  on {}", display_path(&path).display());
+                return false;
+            }
+            Err(error) => println!(
+                "{}: skipping {}, {error}",
+                solver.label(),
+                display_path(&path).display()
+            ),
+        }
+    }
+
+    let seed = options.seed ^ u64::from(solver.day());
+    match find_disagreement(solver, options.inputs, options.max_size, seed) {
+        None => {
+            println!(
+                "{}: {} agree on the fixtures and {} generated inputs",
+                solver.label(),
+                solver.differential_names().join(", "),
+                options.inputs
+            );
+            true
+        }
+        Some(finding) => {
+            report(solver, &finding.disagreements);
+            println!(
+                "  on this input, reproduce with `generate {} {} --seed {}`:\n{}",
+                solver.day(),
+                finding.size,
+                finding.seed,
+                finding.input.trim_end()
+            );
+            false
+        }
+    }
+}
+
+fn report(solver: &dyn DynSolver, disagreements: &[Disagreement]) {
+    for disagreement in disagreements {
+        println!("{}: {disagreement}", solver.label());
+    }
+}
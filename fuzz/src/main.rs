@@ -0,0 +1,186 @@
+//! Fuzzes the day parsers and solvers with mutations of their input fixtures:
+//! `cargo run --release --bin fuzz -- 3 --iterations 10000`.
+//!
+//! Every mutated input is solved with both parts under `catch_unwind` and a time limit. Parse
+//! errors are the expected outcome for malformed input; inputs making a solver panic or exceed
+//! the limit are saved to `fuzz/corpus/day_NN/` for triage, named after their content so that
+//! the same finding is only saved once. The `.params` sidecar of the mutated fixture is saved
+//! next to them, so that `aoc <day> --input-dir fuzz/corpus` solves them the same way. Aborts,
+//! like running out of memory, are not caught.
+
+mod mutate;
+
+use aoc::registry;
+use mutate::mutate;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tae_aoclib2025::{
+    display_path, format_duration, get_files_from_dir, normalize_input, solve_with_timeout,
+    workspace_root, DynSolver, Params, Parts, Rng, SolveError, PARAMS_EXTENSION,
+};
+
+const USAGE: &str = "Usage: fuzz [<day> | <first>..<last> | all] [options]
+  --iterations <n>   mutated inputs per day (default 1000)
+  --timeout <secs>   time limit per input before it counts as hanging (default 2)
+  --seed <n>         seed of the mutations, printed at the start to reproduce a run
+  --corpus <dir>     where crashing and hanging inputs are saved (default fuzz/corpus)";
+
+struct FuzzOptions {
+    iterations: usize,
+    timeout: Duration,
+    seed: u64,
+    corpus: PathBuf,
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!("{USAGE}");
+    std::process::exit(2);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).peekable();
+    let selection = match args.next_if(|arg| !arg.starts_with('-')) {
+        Some(selection) => selection,
+        None => "all".to_string(),
+    };
+    let mut options = FuzzOptions {
+        iterations: 1000,
+        timeout: Duration::from_secs(2),
+        seed: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64,
+        corpus: workspace_root().join("fuzz").join("corpus"),
+    };
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .unwrap_or_else(|| usage_error(&format!("Missing value for {arg}")))
+        };
+        match arg.as_str() {
+            "--iterations" => options.iterations = parse_value(&arg, &value()),
+            "--timeout" => {
+                options.timeout = Duration::try_from_secs_f64(parse_value(&arg, &value()))
+                    .unwrap_or_else(|_| usage_error("Expected a positive number of seconds"))
+            }
+            "--seed" => options.seed = parse_value(&arg, &value()),
+            "--corpus" => options.corpus = PathBuf::from(value()),
+            _ => usage_error(&format!("Unknown option: {arg}")),
+        }
+    }
+    // Solvers exceeding the time limit keep running on a detached thread
+    let registry = Box::leak(Box::new(registry()));
+    let solvers = registry
+        .select(&selection)
+        .unwrap_or_else(|message| usage_error(&message));
+
+    println!("seed {}", options.seed);
+    let mut findings = 0;
+    for solver in solvers {
+        findings += fuzz_day(solver, &options);
+    }
+    if findings > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| usage_error(&format!("Invalid value for {option}: {value}")))
+}
+
+/// Returns the number of new crashing or hanging inputs.
+fn fuzz_day(solver: &'static dyn DynSolver, options: &FuzzOptions) -> usize {
+    let input_dir = workspace_root().join(solver.label()).join("input");
+    let fixtures: Vec<(String, Params)> = get_files_from_dir(&input_dir)
+        .into_iter()
+        .filter_map(|(contents, path)| Some((contents.ok()?, path)))
+        .filter(|(contents, _)| !contents.is_empty())
+        .map(|(contents, path)| {
+            let params = Params::for_input(&path).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                std::process::exit(1);
+            });
+            (contents, params)
+        })
+        .collect();
+    if fixtures.is_empty() {
+        println!("{}: no non-empty fixtures to mutate", solver.label());
+        return 0;
+    }
+    let mut rng = Rng::new(options.seed ^ u64::from(solver.day()));
+    let (mut parse_errors, mut crashes, mut hangs) = (0, 0, 0);
+    let mut iterations = 0;
+    while iterations < options.iterations {
+        iterations += 1;
+        let (fixture, params) = &fixtures[rng.below(fixtures.len())];
+        let input = mutate(fixture, &mut rng);
+        if input.is_empty() {
+            // The runner skips empty inputs
+            continue;
+        }
+        let (normalized, _) = normalize_input(&input, solver.trim_policy());
+        let (kind, reason) =
+            match solve_with_timeout(solver, &normalized, params, Parts::BOTH, options.timeout) {
+                Ok(_) => continue,
+                Err(SolveError::Parse(_)) => {
+                    parse_errors += 1;
+                    continue;
+                }
+                Err(SolveError::Panic(panic)) => ("crash", panic.to_string()),
+                Err(SolveError::Timeout(limit)) => (
+                    "hang",
+                    format!("exceeded the time limit of {}", format_duration(limit)),
+                ),
+            };
+        if let Some(path) = save(&options.corpus.join(solver.label()), kind, &input, params) {
+            println!("{}: {reason}\n  saved {}", solver.label(), path.display());
+            if kind == "crash" {
+                crashes += 1;
+            } else {
+                hangs += 1;
+            }
+        }
+        if kind == "hang" {
+            // The hanging solver keeps a core busy, further inputs would be timed unreliably
+            println!("{}: stopping after a hanging input", solver.label());
+            break;
+        }
+    }
+    println!(
+        "{}: {iterations} inputs, {parse_errors} parse errors, {crashes} new crashes, {hangs} new hangs",
+        solver.label()
+    );
+    crashes + hangs
+}
+
+/// Saves `input` as `<kind>-<hash>.txt` in `dir`, with a `.params` sidecar if there are
+/// params. Returns `None` if it was saved before.
+fn save(dir: &Path, kind: &str, input: &str, params: &Params) -> Option<PathBuf> {
+    let hash = fnv1a(format!("{input}{}", params.contents()).as_bytes());
+    let path = dir.join(format!("{kind}-{hash:016x}.txt"));
+    if path.exists() {
+        return None;
+    }
+    fs::create_dir_all(dir)
+        .and_then(|_| match params.contents() {
+            "" => Ok(()),
+            params => fs::write(path.with_extension(PARAMS_EXTENSION), params),
+        })
+        .and_then(|_| fs::write(&path, input))
+        .unwrap_or_else(|err| {
+            eprintln!("Could not write {}: {err}", path.display());
+            std::process::exit(1);
+        });
+    Some(display_path(&path))
+}
+
+/// A hash that is stable across Rust versions, unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
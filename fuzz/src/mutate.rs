@@ -0,0 +1,63 @@
+use tae_aoclib2025::Rng;
+
+/// Bytes a flip is likely to turn into, chosen to hit the separators and number handling of
+/// the parsers rather than only producing garbage.
+const INTERESTING_BYTES: &[u8] = b"0123456789-+,:=|.#@<>^vOX \n";
+
+/// Applies one to four random byte flips, truncations, line duplications and line deletions.
+/// Mutations may produce invalid UTF-8, which is replaced like a lossy file read would.
+pub fn mutate(input: &str, rng: &mut Rng) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+    for _ in 0..=rng.below(4) {
+        if bytes.is_empty() {
+            break;
+        }
+        match rng.below(4) {
+            0 => flip_byte(&mut bytes, rng),
+            1 => {
+                let len = rng.below(bytes.len());
+                bytes.truncate(len);
+            }
+            2 => duplicate_line(&mut bytes, rng),
+            _ => delete_line(&mut bytes, rng),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn flip_byte(bytes: &mut [u8], rng: &mut Rng) {
+    let position = rng.below(bytes.len());
+    bytes[position] = match rng.below(3) {
+        0 => INTERESTING_BYTES[rng.below(INTERESTING_BYTES.len())],
+        1 => bytes[rng.below(bytes.len())],
+        _ => rng.next_u64() as u8,
+    };
+}
+
+/// The byte range of a random line, including its newline.
+fn random_line(bytes: &[u8], rng: &mut Rng) -> (usize, usize) {
+    let position = rng.below(bytes.len());
+    let start = bytes[..position]
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |newline| newline + 1);
+    let end = bytes[position..]
+        .iter()
+        .position(|byte| *byte == b'\n')
+        .map_or(bytes.len(), |newline| position + newline + 1);
+    (start, end)
+}
+
+fn duplicate_line(bytes: &mut Vec<u8>, rng: &mut Rng) {
+    let (start, end) = random_line(bytes, rng);
+    let mut line = bytes[start..end].to_vec();
+    if line.last() != Some(&b'\n') {
+        line.insert(0, b'\n');
+    }
+    bytes.splice(end..end, line);
+}
+
+fn delete_line(bytes: &mut Vec<u8>, rng: &mut Rng) {
+    let (start, end) = random_line(bytes, rng);
+    bytes.drain(start..end);
+}
@@ -0,0 +1,64 @@
+//! Writes a synthetic input for a day, which unlike real puzzle inputs may be shared:
+//! `cargo run --release --bin generate -- 9 1000000 --seed 7 | cargo run --release --bin aoc -- 9 -`.
+//!
+//! What the size counts depends on the day, see the `generate` methods of the solvers. The seed
+//! is printed to stderr, so that an interesting input can be generated again.
+
+use aoc::registry;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tae_aoclib2025::Rng;
+
+const USAGE: &str = "Usage: generate <day> <size> [options]
+  --seed <n>     seed of the generator (default: the current time)
+  --out <file>   write the input to a file instead of stdout";
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!("{USAGE}");
+    std::process::exit(2);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let (Some(day), Some(size)) = (args.next(), args.next()) else {
+        usage_error("Expected a day and a size");
+    };
+    let day: u32 = parse_value("day", &day);
+    let size: usize = parse_value("size", &size);
+    let mut seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let mut out = None;
+    while let Some(arg) = args.next() {
+        let Some(value) = args.next() else {
+            usage_error(&format!("Missing value for {arg}"));
+        };
+        match arg.as_str() {
+            "--seed" => seed = parse_value(&arg, &value),
+            "--out" => out = Some(value),
+            _ => usage_error(&format!("Unknown option: {arg}")),
+        }
+    }
+    let registry = registry();
+    let Some(solver) = registry.get(day) else {
+        usage_error(&format!("Day {day} is not registered"));
+    };
+
+    eprintln!("seed {seed}");
+    let input = solver.generate(size, &mut Rng::new(seed));
+    match out {
+        Some(path) => fs::write(&path, input).unwrap_or_else(|err| {
+            eprintln!("Could not write {path}: {err}");
+            std::process::exit(1);
+        }),
+        None => print!("{input}"),
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(name: &str, value: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| usage_error(&format!("Invalid {name}: {value}")))
+}
@@ -0,0 +1,118 @@
+//! Scaffolds a new day from `day_template`: `cargo run --bin new_day -- 19`.
+//!
+//! Creates `day_NN` with empty input fixtures and registers it in the workspace members, the
+//! dependencies of `aoc` and its registry. An existing day is never overwritten.
+
+use std::fs;
+use std::path::Path;
+use tae_aoclib2025::workspace_root;
+
+const FIXTURES: [&str; 3] = ["demo.txt", "test1.txt", "test2.txt"];
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!("Usage: new_day <day>");
+    std::process::exit(2);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let day: u32 = match (args.next(), args.next()) {
+        (Some(day), None) => day
+            .parse()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .unwrap_or_else(|| usage_error(&format!("Not a day between 1 and 25: {day}"))),
+        _ => usage_error("Expected exactly one day number"),
+    };
+    if let Err(message) = scaffold(&workspace_root(), day) {
+        eprintln!("{message}");
+        std::process::exit(1);
+    }
+}
+
+fn scaffold(root: &Path, day: u32) -> Result<(), String> {
+    let label = format!("day_{day:02}");
+    let day_dir = root.join(&label);
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+    let members = read(&root.join("Cargo.toml"))?;
+    let aoc_manifest = read(&root.join("aoc/Cargo.toml"))?;
+    let registry = read(&root.join("aoc/src/lib.rs"))?;
+    // Check everything before writing anything, so that a refused day leaves no traces
+    let members = insert_sorted(&members, "\"day_", &format!("\"{label}\","))?;
+    let aoc_manifest = insert_sorted(
+        &aoc_manifest,
+        "day_",
+        &format!("{label} = {{ path = \"../{label}\" }}"),
+    )?;
+    let registry = insert_sorted(
+        &registry,
+        "registry.register(day_",
+        &format!("registry.register({label}::Day{day:02});"),
+    )?;
+
+    let template = root.join("day_template");
+    let substitute = |text: String| {
+        text.replace("day_XX", &label)
+            .replace("DayXX", &format!("Day{day:02}"))
+            .replace("Day XX", &format!("Day {day}"))
+            .replace("XX", &day.to_string())
+    };
+    fs::create_dir_all(day_dir.join("src")).map_err(|err| err.to_string())?;
+    fs::create_dir_all(day_dir.join("input")).map_err(|err| err.to_string())?;
+    for file in ["Cargo.toml", "src/lib.rs", "src/main.rs"] {
+        write(
+            &day_dir.join(file),
+            &substitute(read(&template.join(file))?),
+        )?;
+    }
+    for fixture in FIXTURES {
+        write(&day_dir.join("input").join(fixture), "")?;
+    }
+    write(&root.join("Cargo.toml"), &members)?;
+    write(&root.join("aoc/Cargo.toml"), &aoc_manifest)?;
+    write(&root.join("aoc/src/lib.rs"), &registry)?;
+    println!("Created {label}, run it with `cargo run --release --bin {label}`");
+    Ok(())
+}
+
+/// Inserts `line` into the sorted block of lines starting with `prefix` (ignoring indentation),
+/// indented like its new neighbour. An error if the line is already there.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].trim_start().starts_with(prefix))
+        .collect();
+    let Some(&first) = entries.first() else {
+        return Err(format!("Found no line starting with {prefix}"));
+    };
+    if entries.iter().any(|&i| lines[i].trim() == line) {
+        return Err(format!("{line} is already there"));
+    }
+    let position = entries
+        .iter()
+        .rev()
+        .find(|&&i| lines[i].trim() < line)
+        .map_or(first, |&i| i + 1);
+    let neighbour = lines[entries
+        .iter()
+        .copied()
+        .find(|&i| i >= position)
+        .unwrap_or(position - 1)];
+    let indented = format!(
+        "{}{line}",
+        &neighbour[..neighbour.len() - neighbour.trim_start().len()]
+    );
+    lines.insert(position, &indented);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Could not read {}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("Could not write {}: {err}", path.display()))
+}
@@ -1,8 +1,3 @@
 #!/bin/bash
 
-cargo build --bins
-
-for day in $(seq -w 1 18); do
-  echo "Running day_$day..."
-  cargo run --release --quiet --bin day_$day
-done
+cargo run --release --quiet --bin aoc -- all
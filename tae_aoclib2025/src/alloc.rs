@@ -0,0 +1,109 @@
+//! Opt-in allocation statistics per solved input.
+//!
+//! Build with the `alloc-stats` feature (e.g. `cargo run --release -p aoc --features
+//! tae_aoclib2025/alloc-stats`) to install [`CountingAllocator`] as the global allocator.
+//! Counters are kept per thread, so parallel inputs do not disturb each other's numbers.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The system allocator, counting the allocations of the current thread.
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Set by the first allocation going through [`CountingAllocator`].
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    /// Sum of all allocation sizes, reallocations count with their new size.
+    pub bytes: usize,
+    /// Maximum of the bytes allocated and not yet freed during the measurement.
+    pub peak_bytes: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: usize,
+    bytes: usize,
+    live: isize,
+    peak: isize,
+}
+
+impl Counters {
+    const ZERO: Counters = Counters {
+        allocations: 0,
+        bytes: 0,
+        live: 0,
+        peak: 0,
+    };
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::ZERO) };
+}
+
+fn record_alloc(size: usize) {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        ACTIVE.store(true, Ordering::Relaxed);
+    }
+    // Fails only while the thread is torn down, those allocations are of no interest
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        c.allocations += 1;
+        c.bytes += size;
+        c.live += size as isize;
+        c.peak = c.peak.max(c.live);
+        counters.set(c);
+    });
+}
+
+fn record_dealloc(size: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        c.live -= size as isize;
+        counters.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_alloc(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_alloc(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record_dealloc(layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_dealloc(layout.size());
+        record_alloc(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Runs `f` and counts its allocations on the current thread.
+/// `None` if [`CountingAllocator`] is not the global allocator.
+pub fn measure_allocations<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    COUNTERS.set(Counters::ZERO);
+    let result = f();
+    let counters = COUNTERS.get();
+    let stats = ACTIVE.load(Ordering::Relaxed).then_some(AllocStats {
+        allocations: counters.allocations,
+        bytes: counters.bytes,
+        peak_bytes: counters.peak.max(0) as usize,
+    });
+    (result, stats)
+}
@@ -0,0 +1,135 @@
+use crate::Coordinate;
+use std::fmt::{Display, Formatter};
+
+/// The answer of one part, displayed the way the puzzle expects it to be entered and compared
+/// with an expected answer according to its type.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Answer {
+    Int(i64),
+    /// Integers that do not fit into an `i64`.
+    BigInt(i128),
+    Str(String),
+    /// A position, displayed as `col,row`.
+    Coordinate(Coordinate),
+    /// Displayed comma separated without spaces, e.g. `4,6,3,5`.
+    List(Vec<Answer>),
+    /// A multi-line drawing, e.g. letters formed by pixels. Trailing whitespace and blank lines
+    /// around the drawing are ignored when comparing.
+    Art(String),
+    /// Several outputs of one part, one per line, for days with more than two results.
+    Multiple(Vec<Answer>),
+    /// The day has no such part, like the second part of the last day.
+    None,
+}
+
+impl Answer {
+    /// Whether the answer equals `expected`, as read from an `.expected` sidecar.
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+        match self {
+            Answer::Int(value) => expected.parse::<i64>() == Ok(*value),
+            Answer::BigInt(value) => expected.parse::<i128>() == Ok(*value),
+            Answer::Str(value) => value.trim() == expected,
+            Answer::Coordinate(coordinate) => expected.split_once(',').is_some_and(|(col, row)| {
+                col.trim().parse() == Ok(coordinate.col) && row.trim().parse() == Ok(coordinate.row)
+            }),
+            Answer::List(items) => {
+                let expected: Vec<&str> = expected.split(',').collect();
+                expected.len() == items.len()
+                    && items.iter().zip(expected).all(|(item, e)| item.matches(e))
+            }
+            Answer::Art(art) => art_lines(art) == art_lines(expected),
+            Answer::Multiple(outputs) => {
+                let expected: Vec<&str> = expected.lines().collect();
+                expected.len() == outputs.len()
+                    && outputs
+                        .iter()
+                        .zip(expected)
+                        .all(|(output, e)| output.matches(e))
+            }
+            Answer::None => expected.is_empty(),
+        }
+    }
+}
+
+fn art_lines(art: &str) -> Vec<&str> {
+    let lines: Vec<&str> = art.lines().map(str::trim_end).collect();
+    let first = lines.iter().position(|line| !line.is_empty());
+    let last = lines.iter().rposition(|line| !line.is_empty());
+    match (first, last) {
+        (Some(first), Some(last)) => lines[first..=last].to_vec(),
+        _ => Vec::new(),
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let join = |items: &[Answer], separator: &str| {
+            items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<String>>()
+                .join(separator)
+        };
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::BigInt(value) => write!(f, "{value}"),
+            Answer::Str(value) => write!(f, "{value}"),
+            Answer::Coordinate(coordinate) => write!(f, "{},{}", coordinate.col, coordinate.row),
+            Answer::List(items) => write!(f, "{}", join(items, ",")),
+            Answer::Art(art) => write!(f, "{art}"),
+            Answer::Multiple(outputs) => write!(f, "{}", join(outputs, "\n")),
+            Answer::None => Ok(()),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Int(value),
+                        Err(_) => Answer::BigInt(value as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, u32, i64, u64, usize, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => Answer::from(value),
+            Err(_) => Answer::Str(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl From<Coordinate> for Answer {
+    fn from(value: Coordinate) -> Self {
+        Answer::Coordinate(value)
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(values: Vec<T>) -> Self {
+        Answer::List(values.into_iter().map(Into::into).collect())
+    }
+}
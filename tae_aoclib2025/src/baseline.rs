@@ -0,0 +1,247 @@
+use crate::{csv_field, format_duration, workspace_root, DayReport, PhaseTimings};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const DEFAULT_BASELINE_PATH: &str = "baseline.csv";
+
+#[derive(Debug, Clone)]
+pub struct BaselineOptions {
+    pub path: PathBuf,
+    /// Write the timings of this run to the baseline file, replacing older entries of the same inputs.
+    pub save: bool,
+    /// Slowdown in percent above which a phase is reported as a regression.
+    pub threshold_percent: f64,
+    /// Phases faster than this in both runs are not compared, their timings are mostly noise.
+    pub min_duration: Duration,
+}
+
+impl Default for BaselineOptions {
+    fn default() -> Self {
+        Self {
+            path: workspace_root().join(DEFAULT_BASELINE_PATH),
+            save: false,
+            threshold_percent: 10.0,
+            min_duration: Duration::from_millis(1),
+        }
+    }
+}
+
+/// Timings per day label and input name, stored as CSV:
+/// ```text
+/// day,input,parse_ns,part1_ns,part2_ns
+/// day_06,test2.txt,10543,1203344,48213377
+/// ```
+#[derive(Debug, Default)]
+pub struct Baseline {
+    entries: BTreeMap<(String, String), PhaseTimings>,
+}
+
+const HEADER: &str = "day,input,parse_ns,part1_ns,part2_ns";
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        let mut entries = BTreeMap::new();
+        let invalid = |line_number: usize| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}:{line_number}: invalid baseline entry", path.display()),
+            )
+        };
+        for (line_number, fields) in csv_records(&fs::read_to_string(path)?) {
+            let fields = fields.map_err(|_| invalid(line_number))?;
+            if fields.join(",") == HEADER || fields == [""] {
+                continue;
+            }
+            let nanos = |field: &str| field.parse::<u64>().map(Duration::from_nanos);
+            let [day, input, parse, part1, part2] = &fields[..] else {
+                return Err(invalid(line_number));
+            };
+            let timings = PhaseTimings {
+                parse: nanos(parse).map_err(|_| invalid(line_number))?,
+                part1: nanos(part1).map_err(|_| invalid(line_number))?,
+                part2: nanos(part2).map_err(|_| invalid(line_number))?,
+            };
+            entries.insert((day.clone(), input.clone()), timings);
+        }
+        Ok(Baseline { entries })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = vec![HEADER.to_string()];
+        for ((day, input), timings) in &self.entries {
+            contents.push(format!(
+                "{},{},{},{},{}",
+                csv_field(day),
+                csv_field(input),
+                timings.parse.as_nanos(),
+                timings.part1.as_nanos(),
+                timings.part2.as_nanos()
+            ));
+        }
+        fs::write(path, contents.join("\n") + "\n")
+    }
+
+    pub fn update(&mut self, reports: &[DayReport]) {
+        for report in reports {
+            for input in &report.inputs {
+                if let Some(timings) = input.representative_timings() {
+                    self.entries
+                        .insert((report.label.clone(), input.name.clone()), timings);
+                }
+            }
+        }
+    }
+
+    pub fn compare(
+        &self,
+        reports: &[DayReport],
+        threshold_percent: f64,
+        min_duration: Duration,
+    ) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for report in reports {
+            for input in &report.inputs {
+                let key = (report.label.clone(), input.name.clone());
+                let Some(baseline) = self.entries.get(&key) else {
+                    continue;
+                };
+                let Some(current) = input.representative_timings() else {
+                    continue;
+                };
+                for (phase, before, after) in [
+                    ("parse", baseline.parse, current.parse),
+                    ("part 1", baseline.part1, current.part1),
+                    ("part 2", baseline.part2, current.part2),
+                    ("total", baseline.total(), current.total()),
+                ] {
+                    if before.is_zero() || before.max(after) < min_duration {
+                        continue;
+                    }
+                    let percent = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+                    if percent > threshold_percent {
+                        regressions.push(Regression {
+                            day: report.label.clone(),
+                            input: input.name.clone(),
+                            phase,
+                            baseline: before,
+                            current: after,
+                            percent,
+                        });
+                    }
+                }
+            }
+        }
+        regressions
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Regression {
+    pub day: String,
+    pub input: String,
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+    pub percent: f64,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}: {:.0}% slower ({} -> {})",
+            self.day,
+            self.input,
+            self.phase,
+            self.percent,
+            format_duration(self.baseline),
+            format_duration(self.current)
+        )
+    }
+}
+
+/// Compares the run against the baseline file (if there is one) and saves it if requested.
+/// Returns the lines to report to the user.
+pub fn check_baseline(reports: &[DayReport], options: &BaselineOptions) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut baseline = match Baseline::load(&options.path) {
+        Ok(baseline) => {
+            let regressions =
+                baseline.compare(reports, options.threshold_percent, options.min_duration);
+            let scope = format!(
+                "above {}% (phases under {} ignored) against {}",
+                options.threshold_percent,
+                format_duration(options.min_duration),
+                options.path.display()
+            );
+            if regressions.is_empty() {
+                messages.push(format!("No regressions {scope}"));
+            } else {
+                messages.push(format!("Regressions {scope}:"));
+                messages.extend(regressions.iter().map(|r| format!("  {r}")));
+            }
+            baseline
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Baseline::default(),
+        Err(err) => {
+            messages.push(format!("Could not read baseline: {err}"));
+            return messages;
+        }
+    };
+    if options.save {
+        baseline.update(reports);
+        match baseline.save(&options.path) {
+            Ok(()) => messages.push(format!("Saved baseline to {}", options.path.display())),
+            Err(err) => messages.push(format!("Could not save baseline: {err}")),
+        }
+    }
+    messages
+}
+
+/// The records of a CSV file as written by [`csv_field`], with the 1-based line they start on.
+/// Quoted fields may contain commas, doubled quotes and line breaks. A record with an
+/// unterminated quote or text after a closing quote is an error.
+fn csv_records(contents: &str) -> Vec<(usize, Result<Vec<String>, ()>)> {
+    let mut records = Vec::new();
+    let mut chars = contents.chars().peekable();
+    let mut line_number = 1;
+    while chars.peek().is_some() {
+        let start_line = line_number;
+        let mut fields = vec![String::new()];
+        let mut valid = true;
+        let mut quoted = false;
+        let mut closed = false;
+        while let Some(c) = chars.next() {
+            if c == '\n' {
+                line_number += 1;
+            }
+            let field = fields.last_mut().unwrap();
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' if quoted => {
+                    quoted = false;
+                    closed = true;
+                }
+                '"' if field.is_empty() && !closed => quoted = true,
+                _ if quoted => field.push(c),
+                ',' => {
+                    fields.push(String::new());
+                    closed = false;
+                }
+                '\n' => break,
+                '\r' if chars.peek() == Some(&'\n') => {}
+                _ if closed => valid = false,
+                _ => field.push(c),
+            }
+        }
+        records.push((start_line, (valid && !quoted).then_some(fields).ok_or(())));
+    }
+    records
+}
@@ -0,0 +1,123 @@
+use crate::{PhaseTimings, Solution, SolveError};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+    /// If set, measure for this long per input instead of a fixed number of iterations.
+    pub budget: Option<Duration>,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 50,
+            budget: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty());
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean_secs = sorted.iter().map(|x| x.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|x| (x.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        // Nearest-rank percentile
+        let p95_rank = (n * 95).div_ceil(100);
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95: sorted[p95_rank - 1],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BenchResult {
+    pub warmup: usize,
+    pub samples: Vec<PhaseTimings>,
+}
+
+impl BenchResult {
+    pub fn parse(&self) -> Stats {
+        Stats::from_samples(&self.phase(|t| t.parse))
+    }
+
+    pub fn part1(&self) -> Stats {
+        Stats::from_samples(&self.phase(|t| t.part1))
+    }
+
+    pub fn part2(&self) -> Stats {
+        Stats::from_samples(&self.phase(|t| t.part2))
+    }
+
+    pub fn total(&self) -> Stats {
+        Stats::from_samples(&self.phase(|t| t.total()))
+    }
+
+    pub fn phases(&self) -> [(&'static str, Stats); 4] {
+        [
+            ("parse", self.parse()),
+            ("part1", self.part1()),
+            ("part2", self.part2()),
+            ("total", self.total()),
+        ]
+    }
+
+    fn phase(&self, get: fn(&PhaseTimings) -> Duration) -> Vec<Duration> {
+        self.samples.iter().map(get).collect()
+    }
+}
+
+/// Solves an input repeatedly with `solve`, which should isolate panics like the runner does.
+/// Stops at the first run that fails, solvers may fail only on later runs.
+pub fn bench_input(
+    config: &BenchConfig,
+    mut solve: impl FnMut() -> Result<Solution, SolveError>,
+) -> Result<BenchResult, SolveError> {
+    for _ in 0..config.warmup {
+        solve()?;
+    }
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    loop {
+        samples.push(solve()?.timings);
+        let done = match config.budget {
+            Some(budget) => start.elapsed() >= budget,
+            None => samples.len() >= config.iterations,
+        };
+        if done {
+            break;
+        }
+    }
+    Ok(BenchResult {
+        warmup: config.warmup,
+        samples,
+    })
+}
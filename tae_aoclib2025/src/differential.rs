@@ -0,0 +1,89 @@
+use crate::{catch_panic, Answer, DynSolver, Panic, Rng};
+use std::fmt::{Display, Formatter};
+
+/// Two implementations of the same question on a day's parsed input: a slow but obviously
+/// correct reference, like a brute force, and the optimized version that is validated against it.
+pub struct Differential<P> {
+    /// What is compared, e.g. "part 1: brute force vs reverse search".
+    pub name: &'static str,
+    pub reference: fn(&P) -> Answer,
+    pub fast: fn(&P) -> Answer,
+}
+
+impl<P> Differential<P> {
+    /// Runs both implementations. Returns their outcomes unless both answer the same.
+    pub fn check(&self, parsed: &P) -> Option<Disagreement> {
+        let reference = catch_panic(|| (self.reference)(parsed));
+        let fast = catch_panic(|| (self.fast)(parsed));
+        match (&reference, &fast) {
+            (Ok(reference), Ok(fast)) if reference == fast => None,
+            _ => Some(Disagreement {
+                name: self.name,
+                reference,
+                fast,
+            }),
+        }
+    }
+}
+
+/// The outcomes of the two implementations of a [`Differential`] on an input they disagree on.
+/// A panic counts as an outcome, so a fast implementation panicking is a disagreement as well.
+#[derive(Debug, Clone)]
+pub struct Disagreement {
+    pub name: &'static str,
+    pub reference: Result<Answer, Panic>,
+    pub fast: Result<Answer, Panic>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let outcome = |outcome: &Result<Answer, Panic>| match outcome {
+            Ok(answer) => answer.to_string(),
+            Err(panic) => panic.to_string(),
+        };
+        write!(
+            f,
+            "{}: reference {}, fast {}",
+            self.name,
+            outcome(&self.reference),
+            outcome(&self.fast)
+        )
+    }
+}
+
+/// A generated input on which differentials disagree, reproducible with the `generate` binary.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub size: usize,
+    pub seed: u64,
+    pub input: String,
+    pub disagreements: Vec<Disagreement>,
+}
+
+/// Checks the differentials of `solver` on `count` generated inputs and returns the first one
+/// they disagree on. Sizes cycle through `1..=max_size`, so that small inputs, which are easy to
+/// debug, come first. Input `i` is generated with the seed `seed + i`.
+pub fn find_disagreement(
+    solver: &dyn DynSolver,
+    count: usize,
+    max_size: usize,
+    seed: u64,
+) -> Option<Finding> {
+    for i in 0..count {
+        let size = 1 + i % max_size;
+        let seed = seed.wrapping_add(i as u64);
+        let input = solver.generate(size, &mut Rng::new(seed));
+        let disagreements = solver
+            .check_differentials(&input, &Default::default())
+            .unwrap_or_else(|error| panic!("Generated input does not parse: {error}"));
+        if !disagreements.is_empty() {
+            return Some(Finding {
+                size,
+                seed,
+                input,
+                disagreements,
+            });
+        }
+    }
+    None
+}
@@ -0,0 +1,208 @@
+use crate::{display_path, Answer, ParseError};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::ops::AddAssign;
+use std::path::{Path, PathBuf};
+
+/// Extension of the sidecar file holding the known answers for an input,
+/// e.g. `input/demo.expected` for `input/demo.txt`.
+pub const EXPECTED_EXTENSION: &str = "expected";
+
+/// Known answers for one input file. A missing part means the answer is not known yet.
+///
+/// The sidecar is a list of `key=value` lines, blank lines and lines starting with `#` are ignored.
+/// Multi-line answers use `key<<TERMINATOR` followed by the lines up to the terminator:
+/// ```text
+/// part1=11
+/// part2<<END
+/// #..#
+/// ####
+/// END
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn sidecar_path(input_file: &Path) -> PathBuf {
+        input_file.with_extension(EXPECTED_EXTENSION)
+    }
+
+    /// The known answers of `input_file`, none if it has no sidecar. An error pointing into the
+    /// sidecar if it is malformed.
+    pub fn for_input(input_file: &Path) -> Result<Expected, ParseError> {
+        let path = Self::sidecar_path(input_file);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Expected::default()),
+            Err(err) => {
+                let mut error = ParseError::new(1, 1, "a readable UTF-8 file", err.to_string());
+                error.file = Some(display_path(&path));
+                return Err(error);
+            }
+        };
+        Self::parse(&contents).map_err(|error| error.with_source(&display_path(&path), &contents))
+    }
+
+    pub fn parse(contents: &str) -> Result<Expected, ParseError> {
+        let mut expected = Expected::default();
+        let mut lines = contents.lines();
+        while let Some(line) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = match line.split_once("<<") {
+                Some((key, terminator)) => {
+                    let terminator = terminator.trim();
+                    let mut value = Vec::new();
+                    loop {
+                        match lines.next() {
+                            Some(next) if next.trim() == terminator => break,
+                            Some(next) => value.push(next),
+                            // A misspelled terminator must not swallow the rest of the file
+                            None => {
+                                return Err(ParseError::at(
+                                    contents,
+                                    line,
+                                    format!("the terminator `{terminator}` closing this answer"),
+                                ))
+                            }
+                        }
+                    }
+                    (key, value.join("\n"))
+                }
+                None => {
+                    let (key, value) = line
+                        .split_once('=')
+                        .ok_or_else(|| ParseError::at(contents, line, "a key=value line"))?;
+                    (key, value.trim().to_string())
+                }
+            };
+            let value = Some(value);
+            match key.trim() {
+                "part1" => expected.part1 = value,
+                "part2" => expected.part2 = value,
+                _ => return Err(ParseError::at(contents, key.trim(), "part1 or part2")),
+            }
+        }
+        Ok(expected)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+    /// The part was not run.
+    Skipped,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, actual: Option<&Answer>) -> Verdict {
+        match (expected, actual) {
+            (_, None) => Verdict::Skipped,
+            (None, Some(_)) => Verdict::Unknown,
+            (Some(expected), Some(actual)) if actual.matches(expected) => Verdict::Pass,
+            (Some(_), Some(_)) => Verdict::Fail,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+            Verdict::Skipped => "SKIPPED",
+        };
+        f.pad(text)
+    }
+}
+
+/// Line-by-line diff of an expected and an actual answer, one `-`/`+` pair per differing line.
+pub fn format_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.trim().lines().collect();
+    let mut diff = Vec::new();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        let expected_line = expected_lines.get(i);
+        let actual_line = actual_lines.get(i);
+        if expected_line == actual_line {
+            diff.push(format!("    {}", expected_line.unwrap()));
+            continue;
+        }
+        if let Some(line) = expected_line {
+            diff.push(format!("  - {line}"));
+        }
+        if let Some(line) = actual_line {
+            diff.push(format!("  + {line}"));
+        }
+    }
+    diff.join("\n")
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VerdictSummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize,
+    /// Inputs whose solver panicked; their parts are not counted as verdicts.
+    pub panicked: usize,
+    /// Inputs that failed to parse, likewise not counted as verdicts.
+    pub parse_errors: usize,
+    /// Inputs whose solver exceeded the time limit, likewise not counted as verdicts.
+    pub timeouts: usize,
+}
+
+impl VerdictSummary {
+    pub fn add(&mut self, verdict: Verdict) {
+        match verdict {
+            Verdict::Pass => self.passed += 1,
+            Verdict::Fail => self.failed += 1,
+            Verdict::Unknown => self.unknown += 1,
+            Verdict::Skipped => {}
+        }
+    }
+
+    /// No answer mismatched, no solver panicked or timed out and every input parsed.
+    pub fn success(&self) -> bool {
+        self.failed == 0 && self.panicked == 0 && self.parse_errors == 0 && self.timeouts == 0
+    }
+}
+
+impl AddAssign for VerdictSummary {
+    fn add_assign(&mut self, rhs: Self) {
+        self.passed += rhs.passed;
+        self.failed += rhs.failed;
+        self.unknown += rhs.unknown;
+        self.panicked += rhs.panicked;
+        self.parse_errors += rhs.parse_errors;
+        self.timeouts += rhs.timeouts;
+    }
+}
+
+impl Display for VerdictSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unknown",
+            self.passed, self.failed, self.unknown
+        )?;
+        if self.panicked > 0 {
+            write!(f, ", {} panicked", self.panicked)?;
+        }
+        if self.parse_errors > 0 {
+            write!(f, ", {} parse errors", self.parse_errors)?;
+        }
+        if self.timeouts > 0 {
+            write!(f, ", {} timed out", self.timeouts)?;
+        }
+        Ok(())
+    }
+}
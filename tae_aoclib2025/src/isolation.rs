@@ -0,0 +1,66 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// A panic caught while solving an input.
+#[derive(Debug, Clone)]
+pub struct Panic {
+    pub message: String,
+    /// `file:line:column` of the panic, if known.
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs `f`, turning a panic into an `Err` instead of unwinding further.
+/// The default panic message is suppressed while `f` runs; it is reported through the `Err` instead.
+pub fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, Panic> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LAST_PANIC.set(Some(Panic {
+                    message: payload_message(info.payload()),
+                    location: info.location().map(|location| location.to_string()),
+                }));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+    result.map_err(|payload| {
+        LAST_PANIC.take().unwrap_or_else(|| Panic {
+            message: payload_message(payload.as_ref()),
+            location: None,
+        })
+    })
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "<non-string panic payload>".to_string()
+    }
+}
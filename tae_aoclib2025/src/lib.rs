@@ -1,41 +1,59 @@
+mod alloc;
+mod answer;
+mod baseline;
+mod bench;
+mod differential;
+mod expected;
+mod glob;
+mod isolation;
+mod log;
+mod normalize;
+mod options;
+mod output;
+mod params;
+mod parse_error;
+mod pool;
+mod profile;
+mod report;
+mod rng;
+mod runner;
+mod solver;
+mod watch;
+mod workspace;
+
+pub use alloc::{measure_allocations, AllocStats, CountingAllocator};
+pub use answer::Answer;
+pub use baseline::{check_baseline, Baseline, BaselineOptions, Regression, DEFAULT_BASELINE_PATH};
+pub use bench::{bench_input, BenchConfig, BenchResult, Stats};
+pub use differential::{find_disagreement, Differential, Disagreement, Finding};
+pub use expected::{format_diff, Expected, Verdict, VerdictSummary, EXPECTED_EXTENSION};
+pub use glob::glob_match;
+pub use isolation::{catch_panic, Panic};
+pub use log::{init_logging, log_enabled, write_log, Level, LogConfig, LogFilter, LOG_ENV};
+pub use normalize::{normalize_input, TrimPolicy};
+pub use options::{RunOptions, OPTIONS_USAGE};
+pub use output::{
+    csv_field, format_csv, format_json, json_string, print_day_table, OutputFormat,
+    JSON_SCHEMA_VERSION,
+};
+pub use params::{Params, PARAMS_EXTENSION};
+pub use parse_error::{expect_next, expect_prefix, expect_split_once, parse_token, ParseError};
+pub use profile::{profile_input, ProfileLimit, ProfileResult};
+pub use report::{
+    format_html, format_markdown, html_escape, write_reports, HTML_REPORT, MARKDOWN_REPORT,
+};
+pub use rng::Rng;
+pub use runner::{
+    get_files_from_dir, run_solvers, solve_all_inputs, solve_with_timeout, DayReport, InputReport,
+    SolveError,
+};
+pub use solver::{DynSolver, Parts, PhaseTimings, Registry, Solution, Solver};
+pub use watch::watch;
+pub use workspace::{display_path, workspace_root};
+
 use std::fmt::{Display, Formatter};
-use std::fs;
 use std::ops::{Add, AddAssign, Rem, RemAssign};
-use std::path::{Path, PathBuf};
-use std::time::{Duration, Instant};
-
-pub fn solve_all_inputs(day: &str, do_task: fn(&String) -> (String, String)) {
-    let input_dir: String = if Path::new("input").exists() {
-        "input".to_string()
-    } else {
-        format!("{}/input", day)
-    };
-    let files = get_files_from_dir(Path::new(&input_dir));
-    for (file_contents, test_name) in files {
-        let start = Instant::now();
-        let result = do_task(&file_contents);
-        let duration = start.elapsed();
-        let formatted_result = format!("( {} ) ( {} )", result.0, result.1);
-        let formatted_duration = format_duration(duration);
-        println!(
-            "{:25} {:>25} in {}",
-            test_name.to_str().unwrap(),
-            formatted_result,
-            formatted_duration
-        );
-    }
-}
-
-pub fn get_files_from_dir(input_dir: &Path) -> Vec<(String, PathBuf)> {
-    let mut inputs: Vec<(String, PathBuf)> = Vec::new();
-    fs::read_dir(input_dir)
-        .unwrap()
-        .map(|x| x.unwrap().path())
-        .map(|x| (fs::read_to_string(&x).unwrap(), x))
-        .filter(|(file_content, _name)| !file_content.is_empty())
-        .for_each(|x| inputs.push(x));
-    inputs
-}
+use std::time::Duration;
 
 pub fn format_duration(duration: Duration) -> String {
     let duration_secs = duration.as_secs();
@@ -51,6 +69,21 @@ pub fn format_duration(duration: Duration) -> String {
     format!("{}.{:0>3} micros", duration_micros, duration_nanos)
 }
 
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Coordinate {
     pub col: usize,
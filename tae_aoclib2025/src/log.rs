@@ -0,0 +1,224 @@
+//! Leveled debug logging for the solvers and the runner.
+//!
+//! Messages go to stderr (or a file), never to stdout, so they do not mix with the results.
+//! Which messages are written is decided by a filter like `RUST_LOG`: a comma separated list of
+//! a default level and `target=level` directives, e.g. `info,day_16=trace,tae_aoclib2025::runner=debug`.
+//! A target is a module path prefix (each day crate is its own module, `day_16`) and the most
+//! specific matching directive wins. Debug and trace messages are additionally suppressed while
+//! solving inputs longer than [`LogConfig::max_input_len`], so real inputs do not flood the output.
+
+use std::cell::Cell;
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, RwLock};
+
+/// Name of the environment variable holding the filter if `--log` is not given.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Level::Off),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("expected off, info, debug or trace, found {s}")),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Level::Off => "OFF",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    pub default: Level,
+    /// `(target, level)` pairs, targets being module path prefixes like `day_07`.
+    pub directives: Vec<(String, Level)>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        LogFilter {
+            default: Level::Off,
+            directives: Vec::new(),
+        }
+    }
+}
+
+impl LogFilter {
+    pub fn level_for(&self, module: &str) -> Level {
+        self.directives
+            .iter()
+            .filter(|(target, _)| {
+                module == target
+                    || module
+                        .strip_prefix(target.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .max_by_key(|(target, _)| target.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    /// The most verbose level of any module.
+    pub fn max_level(&self) -> Level {
+        self.directives
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = LogFilter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    filter.directives.push((target.to_string(), level.parse()?));
+                }
+                None => filter.default = directive.parse()?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogConfig {
+    pub filter: LogFilter,
+    /// Debug and trace messages are dropped while solving inputs longer than this (in bytes).
+    pub max_input_len: Option<usize>,
+    /// Write to this file instead of stderr.
+    pub file: Option<PathBuf>,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        LogConfig {
+            filter: LogFilter::default(),
+            max_input_len: Some(10_000),
+            file: None,
+        }
+    }
+}
+
+static CONFIG: RwLock<Option<LogConfig>> = RwLock::new(None);
+/// [`LogFilter::max_level`] of the active config, so that disabled messages, e.g. trace messages
+/// in parser loops, cost a relaxed load instead of taking the config lock.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+static LOG_FILE: Mutex<Option<File>> = Mutex::new(None);
+
+thread_local! {
+    static INPUT_LEN: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Activates `config` for all following log calls.
+pub fn init_logging(config: &LogConfig) -> io::Result<()> {
+    let file = match &config.file {
+        Some(path) => Some(File::create(path)?),
+        None => None,
+    };
+    *LOG_FILE.lock().unwrap() = file;
+    *CONFIG.write().unwrap() = Some(config.clone());
+    MAX_LEVEL.store(config.filter.max_level() as u8, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Remembers the length of the input solved on this thread for the input-size guard.
+pub(crate) fn set_input_len(input_len: usize) {
+    INPUT_LEN.set(input_len);
+}
+
+/// Whether a message of `level` from `module` would be written; use [`log_enabled!`] instead.
+pub fn log_enabled(level: Level, module: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    let config = CONFIG.read().unwrap();
+    let Some(config) = config.as_ref() else {
+        return false;
+    };
+    if level > config.filter.level_for(module) {
+        return false;
+    }
+    level == Level::Info
+        || config
+            .max_input_len
+            .is_none_or(|max_input_len| INPUT_LEN.get() <= max_input_len)
+}
+
+/// Writes a message that already passed [`log_enabled`]; use the `log_*!` macros instead.
+pub fn write_log(level: Level, module: &str, args: fmt::Arguments) {
+    let line = format!("[{level:5} {module}] {args}\n");
+    match LOG_FILE.lock().unwrap().as_mut() {
+        Some(file) => {
+            let _ = file.write_all(line.as_bytes());
+        }
+        None => eprint!("{line}"),
+    }
+}
+
+/// Whether messages of the given level are currently written from the calling module, to
+/// avoid building expensive debug output for nothing.
+#[macro_export]
+macro_rules! log_enabled {
+    ($level:expr) => {
+        $crate::log_enabled($level, module_path!())
+    };
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log_enabled($level, module_path!()) {
+            $crate::write_log($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::Level::Info, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::Level::Debug, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! log_trace {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::Level::Trace, $($arg)+)
+    };
+}
@@ -0,0 +1,46 @@
+/// How much trailing whitespace a day wants removed from its inputs, see [`normalize_input`].
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum TrimPolicy {
+    /// Only line endings and a byte order mark are normalized.
+    #[default]
+    Keep,
+    /// Remove whitespace (including newlines) at the end of the input.
+    TrimEnd,
+    /// Remove whitespace at the end of every line and of the input.
+    TrimLines,
+}
+
+/// Strips a UTF-8 byte order mark, converts CRLF line endings to LF and trims trailing
+/// whitespace according to `policy`. Returns the normalized text and what was changed, not
+/// counting the removal of the final newline, which every well-formed file has.
+pub fn normalize_input(input: &str, policy: TrimPolicy) -> (String, Vec<&'static str>) {
+    let mut changes = Vec::new();
+    let mut text = match input.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.push("stripped a byte order mark");
+            rest
+        }
+        None => input,
+    }
+    .to_string();
+    if text.contains("\r\n") {
+        changes.push("converted CRLF line endings");
+        text = text.replace("\r\n", "\n");
+    }
+    let trimmed = match policy {
+        TrimPolicy::Keep => return (text, changes),
+        TrimPolicy::TrimEnd => text.trim_end().to_string(),
+        TrimPolicy::TrimLines => text
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<&str>>()
+            .join("\n")
+            .trim_end()
+            .to_string(),
+    };
+    let final_newline_only = trimmed.len() + 1 == text.len() && text.ends_with('\n');
+    if trimmed != text && !final_newline_only {
+        changes.push("trimmed trailing whitespace");
+    }
+    (trimmed, changes)
+}
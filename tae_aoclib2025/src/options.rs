@@ -0,0 +1,230 @@
+use crate::{
+    BaselineOptions, BenchConfig, Level, LogConfig, OutputFormat, Parts, ProfileLimit, LOG_ENV,
+};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+pub const OPTIONS_USAGE: &str = "\
+Inputs:
+  <input>...            Only run inputs whose name (e.g. demo.txt) or path matches; * and ? allowed
+  -                     Read a single input from stdin instead
+Options:
+  --input-dir <dir>     Read inputs from dir, or from dir/day_NN if that exists
+  --part <1|2>          Only run one part of each day
+  -v, --verbose         Debug output of the solvers (-vv: trace output), same as --log debug
+  --log <filter>        Log filter like debug,day_16=trace (default from AOC_LOG)
+  --log-file <path>     Write log messages to path instead of stderr
+  --log-max-input <n>   Drop debug and trace messages for inputs over n bytes (default 10000, 0 = no limit)
+  --format <format>     Output format: table (default), json or csv
+  --watch               Run again whenever an input file is added or changed
+  --profile <limit>     Solve the one selected input in a silent loop for external profilers,
+                        10000 times or for 5s, then print the average timings
+  --report <dir>        Also write report.md and report.html with all results into dir
+  --bench               Benchmark every input after solving it
+  --warmup <n>          Untimed warm-up runs per input (default 3, implies --bench)
+  --iterations <n>      Timed runs per input (default 50, implies --bench)
+  --budget <seconds>    Time budget per input instead of a fixed iteration count (implies --bench)
+  --timeout <seconds>   Give up on inputs taking longer, reporting them as TIMEOUT
+  --jobs <n>            Solve up to n inputs in parallel (default 1, 0 = one per CPU), not with --bench
+  --baseline <path>     Baseline file to compare timings against (default <workspace>/baseline.csv)
+  --save-baseline       Store the timings of this run in the baseline file
+  --threshold <percent> Slowdown reported as a regression (default 10)
+  --min-duration <ms>   Ignore phases faster than this against the baseline (default 1)";
+
+/// Options shared by the single-day binaries and the `aoc` dispatcher.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    pub bench: Option<BenchConfig>,
+    pub format: OutputFormat,
+    /// Directory to write the Markdown and HTML reports to.
+    pub report_dir: Option<PathBuf>,
+    pub baseline: BaselineOptions,
+    /// Time limit per input, unlimited if `None`.
+    pub timeout: Option<Duration>,
+    /// Number of worker threads solving inputs concurrently.
+    pub jobs: usize,
+    /// Glob patterns selecting inputs, all inputs if empty.
+    pub inputs: Vec<String>,
+    /// Solve the text read from stdin instead of the input files.
+    pub stdin: bool,
+    /// Solve a single input in a loop instead of reporting results.
+    pub profile: Option<ProfileLimit>,
+    /// Re-run whenever the inputs change, until interrupted.
+    pub watch: bool,
+    pub input_dir: Option<PathBuf>,
+    pub parts: Parts,
+    pub log: LogConfig,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            bench: None,
+            format: OutputFormat::default(),
+            report_dir: None,
+            baseline: BaselineOptions::default(),
+            timeout: None,
+            jobs: 1,
+            inputs: Vec::new(),
+            stdin: false,
+            watch: false,
+            profile: None,
+            input_dir: None,
+            parts: Parts::BOTH,
+            log: LogConfig::default(),
+        }
+    }
+}
+
+impl RunOptions {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<RunOptions, String> {
+        let mut options = RunOptions::default();
+        if let Ok(filter) = std::env::var(LOG_ENV) {
+            options.log.filter = filter
+                .parse()
+                .map_err(|err| format!("Invalid value for {LOG_ENV}: {filter} ({err})"))?;
+        }
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    options.format = parse_value(&arg, args.next())?;
+                }
+                "--watch" => {
+                    options.watch = true;
+                }
+                "--profile" => {
+                    options.profile = Some(parse_value(&arg, args.next())?);
+                }
+                "--report" => {
+                    options.report_dir = Some(parse_value(&arg, args.next())?);
+                }
+                "--bench" => {
+                    options.bench_config();
+                }
+                "--warmup" => {
+                    let warmup = parse_value(&arg, args.next())?;
+                    options.bench_config().warmup = warmup;
+                }
+                "--iterations" => {
+                    let iterations = parse_value(&arg, args.next())?;
+                    if iterations == 0 {
+                        return Err("--iterations must be at least 1".to_string());
+                    }
+                    options.bench_config().iterations = iterations;
+                }
+                "--budget" => {
+                    let seconds: f64 = parse_value(&arg, args.next())?;
+                    let budget = Duration::try_from_secs_f64(seconds)
+                        .map_err(|_| format!("Invalid budget: {seconds}"))?;
+                    options.bench_config().budget = Some(budget);
+                }
+                "--timeout" => {
+                    let seconds: f64 = parse_value(&arg, args.next())?;
+                    let timeout = Duration::try_from_secs_f64(seconds)
+                        .ok()
+                        .filter(|timeout| !timeout.is_zero())
+                        .ok_or(format!("Invalid timeout: {seconds}"))?;
+                    options.timeout = Some(timeout);
+                }
+                "--jobs" => {
+                    options.jobs = match parse_value(&arg, args.next())? {
+                        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+                        jobs => jobs,
+                    };
+                }
+                "--baseline" => {
+                    options.baseline.path = parse_value::<PathBuf>(&arg, args.next())?;
+                }
+                "--save-baseline" => {
+                    options.baseline.save = true;
+                }
+                "--threshold" => {
+                    let threshold: f64 = parse_value(&arg, args.next())?;
+                    if threshold.is_nan() || threshold < 0.0 {
+                        return Err(format!("Invalid threshold: {threshold}"));
+                    }
+                    options.baseline.threshold_percent = threshold;
+                }
+                "--min-duration" => {
+                    let millis: f64 = parse_value(&arg, args.next())?;
+                    options.baseline.min_duration = Duration::try_from_secs_f64(millis / 1000.0)
+                        .map_err(|_| format!("Invalid minimum duration: {millis}"))?;
+                }
+                "--input-dir" => {
+                    options.input_dir = Some(parse_value(&arg, args.next())?);
+                }
+                "--part" => {
+                    options.parts = match parse_value(&arg, args.next())? {
+                        part @ (1 | 2) => Parts::only(part),
+                        part => return Err(format!("Invalid part: {part}")),
+                    };
+                }
+                "--verbose" => {
+                    options.increase_verbosity(1);
+                }
+                // -v, -vv, ...
+                short
+                    if short.len() > 1
+                        && short
+                            .strip_prefix('-')
+                            .is_some_and(|vs| vs.chars().all(|c| c == 'v')) =>
+                {
+                    options.increase_verbosity(short.len() - 1);
+                }
+                "--log" => {
+                    options.log.filter = parse_value(&arg, args.next())?;
+                }
+                "--log-file" => {
+                    options.log.file = Some(parse_value(&arg, args.next())?);
+                }
+                "--log-max-input" => {
+                    options.log.max_input_len = match parse_value(&arg, args.next())? {
+                        0 => None,
+                        max_input_len => Some(max_input_len),
+                    };
+                }
+                "-" => {
+                    options.stdin = true;
+                }
+                _ if !arg.starts_with('-') => {
+                    options.inputs.push(arg);
+                }
+                _ => return Err(format!("Unknown argument: {arg}")),
+            }
+        }
+        if options.watch && options.stdin {
+            return Err("--watch cannot be combined with reading from stdin".to_string());
+        }
+        Ok(options)
+    }
+
+    /// Each `-v` raises the default log level by one step, from info to debug to trace.
+    fn increase_verbosity(&mut self, steps: usize) {
+        let mut level = self.log.filter.default.max(Level::Info);
+        for _ in 0..steps {
+            level = match level {
+                Level::Off | Level::Info => Level::Debug,
+                Level::Debug | Level::Trace => Level::Trace,
+            };
+        }
+        self.log.filter.default = level;
+    }
+
+    fn bench_config(&mut self) -> &mut BenchConfig {
+        self.bench.get_or_insert_with(BenchConfig::default)
+    }
+}
+
+fn parse_value<T: FromStr>(name: &str, value: Option<String>) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    let value = value.ok_or(format!("Missing value for {name}"))?;
+    value
+        .parse::<T>()
+        .map_err(|err| format!("Invalid value for {name}: {value} ({err})"))
+}
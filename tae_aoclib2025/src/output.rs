@@ -0,0 +1,513 @@
+//! Rendering of run results as a human readable table, JSON or CSV.
+//!
+//! # JSON schema (version 2)
+//!
+//! Durations are integer nanoseconds. Within a `schema_version`, objects may gain fields,
+//! `verdict` may gain values and `summary` may gain counts; consumers must ignore fields and
+//! values they do not know. Renaming or removing a field, or changing its type, bumps the version.
+//!
+//! ```text
+//! {
+//!   "schema_version": 2,
+//!   "days": [
+//!     {
+//!       "day": 7,
+//!       "name": "Bridge Repair",
+//!       "inputs": [
+//!         {
+//!           "input": "day_07/input/demo.txt",
+//!           "timings_ns": null | { "parse": 1200, "part1": 800, "part2": 950, "total": 2950 },
+//!           "parts": [
+//!             { "part": 1, "answer": "3749", "expected": "3749", "verdict": "pass" },
+//!             { "part": 2, "answer": "11387", "expected": null, "verdict": "unknown" }
+//!           ],
+//!           "bench": null | {
+//!             "warmup": 3,
+//!             "iterations": 50,
+//!             "parse": { "min_ns": .., "median_ns": .., "mean_ns": .., "stddev_ns": .., "p95_ns": .. },
+//!             "part1": { .. }, "part2": { .. }, "total": { .. }
+//!           },
+//!           "panic": null | { "message": "explicit panic", "location": "day_07/src/lib.rs:12:5" },
+//!           "parse_error": null | { "line": 3, "column": 5, "expected": "a number", "found": "`x`" },
+//!           "allocations": null | { "count": 120, "bytes": 40960, "peak_bytes": 16384 },
+//!           "timeout_ns": null | 10000000000
+//!         }
+//!       ],
+//!       "empty_inputs": ["test1.txt"],
+//!       "summary": { "passed": 1, "failed": 0, "unknown": 1, "panicked": 0, "parse_errors": 0, "timeouts": 0 }
+//!     }
+//!   ],
+//!   "summary": { "passed": 1, "failed": 0, "unknown": 1, "panicked": 0, "parse_errors": 0, "timeouts": 0 }
+//! }
+//! ```
+//!
+//! If the solver panicked, `timings_ns` and the answers are `null`, the verdicts are `"panicked"`
+//! and `panic` describes the panic. Version 2 added `panic` and the `panicked` counts; the
+//! `parse_error`, `skipped` and `timeout` verdicts, the `parse_error`, `empty_inputs`,
+//! `allocations` and `timeout_ns` fields and the `parse_errors` and `timeouts` counts were added
+//! later within version 2.
+//! Inputs that do not parse are reported the same way, with `"parse_error"` verdicts and
+//! `parse_error` set, including inputs whose `.expected` or `.params` sidecar is malformed.
+//! `empty_inputs` lists the empty input files that were skipped.
+//! Parts skipped with `--part` have a `null` answer and a `"skipped"` verdict, as have parts a
+//! day does not have. Answers are in their canonical text form, multi-line answers contain `\n`.
+//! `allocations` is only set when built with the `alloc-stats` feature.
+//! Inputs exceeding `--timeout` are reported like panics, with `"timeout"` verdicts and the
+//! exceeded limit in `timeout_ns`.
+//!
+//! # CSV columns
+//!
+//! One row per day, input and part, with a header row:
+//! `day,name,input,part,answer,expected,verdict,parse_ns,part_ns,bench_min_ns,bench_median_ns,bench_mean_ns,bench_stddev_ns,bench_p95_ns,panic,parse_error,allocations,allocated_bytes,peak_bytes,timeout_ns`.
+//! The `bench_*` columns refer to the part's phase and are empty unless benchmarking.
+//! `panic` and `parse_error` are empty unless the input could not be solved, in which case answers
+//! and timings are empty. Parts skipped with `--part` have an empty answer and a `skipped` verdict.
+//! The allocation columns count the whole input and are empty without the `alloc-stats` feature.
+//! `timeout_ns` is the exceeded time limit if the input timed out.
+
+use crate::{
+    format_bytes, format_diff, format_duration, Answer, DayReport, InputReport, Solution,
+    SolveError, Stats, Verdict, VerdictSummary,
+};
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const JSON_SCHEMA_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err("expected json, csv or table".to_string()),
+        }
+    }
+}
+
+pub fn print_day_table(report: &DayReport) {
+    // The allocation columns are only printed if the allocations were counted
+    let show_allocations = report.inputs.iter().any(|input| {
+        input
+            .solution
+            .as_ref()
+            .is_ok_and(|solution| solution.allocations.is_some())
+    });
+    let no_allocations = show_allocations.then_some(["", "", ""]);
+    print_row(
+        [
+            "input", "answers", "parse", "part 1", "part 2", "total", "verdicts",
+        ],
+        show_allocations.then_some(["allocs", "allocated", "peak"]),
+    );
+    for input in &report.inputs {
+        let solution = match &input.solution {
+            Ok(solution) => solution,
+            Err(error) => {
+                let (verdict, details) = match error {
+                    SolveError::Parse(error) => ("PARSE ERROR", error.render()),
+                    SolveError::Panic(panic) => ("PANICKED", panic.to_string()),
+                    SolveError::Timeout(_) => ("TIMEOUT", error.to_string()),
+                };
+                print_row(
+                    [
+                        &input.input.to_string_lossy(),
+                        "FAILED",
+                        "",
+                        "",
+                        "",
+                        "",
+                        verdict,
+                    ],
+                    no_allocations,
+                );
+                for line in details.lines() {
+                    println!("  {line}");
+                }
+                continue;
+            }
+        };
+        let allocations = solution.allocations.map(|stats| {
+            [
+                stats.allocations.to_string(),
+                format_bytes(stats.bytes),
+                format_bytes(stats.peak_bytes),
+            ]
+        });
+        print_row(
+            [
+                &input.input.to_string_lossy(),
+                &format!(
+                    "( {} ) ( {} )",
+                    table_answer(&solution.part1),
+                    table_answer(&solution.part2)
+                ),
+                &format_duration(solution.timings.parse),
+                &format_duration(solution.timings.part1),
+                &format_duration(solution.timings.part2),
+                &format_duration(solution.timings.total()),
+                &format!("{:7} {}", input.verdicts[0], input.verdicts[1]),
+            ],
+            match &allocations {
+                Some(columns) => Some(columns.each_ref().map(String::as_str)),
+                None => no_allocations,
+            },
+        );
+        for (part, expected, actual, verdict) in [
+            (1, &input.expected.part1, &solution.part1, input.verdicts[0]),
+            (2, &input.expected.part2, &solution.part2, input.verdicts[1]),
+        ] {
+            if verdict == Verdict::Fail {
+                println!("  part {part} mismatch:");
+                println!(
+                    "{}",
+                    format_diff(
+                        expected.as_ref().unwrap(),
+                        &actual.as_ref().unwrap().to_string()
+                    )
+                );
+            } else if let Some(answer) = actual.as_ref().filter(|answer| is_multi_line(answer)) {
+                println!("  part {part}:");
+                for line in answer.to_string().lines() {
+                    println!("    {line}");
+                }
+            }
+        }
+        if let Some(bench) = &input.bench {
+            println!(
+                "  {} iterations after {} warm-up runs",
+                bench.samples.len(),
+                bench.warmup
+            );
+            print_bench_row(["phase", "min", "median", "mean", "stddev", "p95"]);
+            for (phase, stats) in bench.phases() {
+                print_bench_row([
+                    phase,
+                    &format_duration(stats.min),
+                    &format_duration(stats.median),
+                    &format_duration(stats.mean),
+                    &format_duration(stats.stddev),
+                    &format_duration(stats.p95),
+                ]);
+            }
+        }
+    }
+    if !report.empty_inputs.is_empty() {
+        println!("skipped empty inputs: {}", report.empty_inputs.join(", "));
+    }
+    println!("{} ({}): {}", report.label, report.name, report.summary());
+}
+
+/// Whether the answer does not fit into a table cell, like [`Answer::Art`] drawings.
+fn is_multi_line(answer: &Answer) -> bool {
+    answer.to_string().contains('\n')
+}
+
+/// Multi-line answers are printed below the row instead.
+fn table_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        None => "-".to_string(),
+        Some(answer) if is_multi_line(answer) => "see below".to_string(),
+        Some(answer) => answer.to_string(),
+    }
+}
+
+fn print_row(
+    [input, answers, parse, part1, part2, total, verdicts]: [&str; 7],
+    allocations: Option<[&str; 3]>,
+) {
+    let allocations = match allocations {
+        Some([count, bytes, peak]) => format!(" {count:>10} {bytes:>11} {peak:>11}"),
+        None => String::new(),
+    };
+    println!(
+        "{input:25} {answers:>25} {parse:>14} {part1:>14} {part2:>14} {total:>14}{allocations}  {verdicts}"
+    );
+}
+
+fn print_bench_row([phase, min, median, mean, stddev, p95]: [&str; 6]) {
+    println!("  {phase:8} {min:>14} {median:>14} {mean:>14} {stddev:>14} {p95:>14}");
+}
+
+pub fn format_json(reports: &[DayReport]) -> String {
+    let mut total = VerdictSummary::default();
+    let mut days = Vec::new();
+    for report in reports {
+        total += report.summary();
+        let mut inputs = Vec::new();
+        for input in &report.inputs {
+            let (timings, answers) = match &input.solution {
+                Ok(solution) => {
+                    let timings = &solution.timings;
+                    let timings = format!(
+                        "{{\"parse\":{},\"part1\":{},\"part2\":{},\"total\":{}}}",
+                        timings.parse.as_nanos(),
+                        timings.part1.as_nanos(),
+                        timings.part2.as_nanos(),
+                        timings.total().as_nanos()
+                    );
+                    (
+                        timings,
+                        [&solution.part1, &solution.part2].map(|answer| {
+                            answer.as_ref().map_or("null".to_string(), |answer| {
+                                json_string(&answer.to_string())
+                            })
+                        }),
+                    )
+                }
+                Err(_) => ("null".to_string(), ["null".to_string(), "null".to_string()]),
+            };
+            let parts = [
+                (1, &answers[0], &input.expected.part1),
+                (2, &answers[1], &input.expected.part2),
+            ]
+            .iter()
+            .zip(input.verdicts)
+            .map(|((part, answer, expected), verdict)| {
+                format!(
+                    "{{\"part\":{part},\"answer\":{answer},\"expected\":{},\"verdict\":{}}}",
+                    expected.as_deref().map_or("null".to_string(), json_string),
+                    json_string(verdict_name(input, verdict))
+                )
+            })
+            .collect::<Vec<String>>();
+            let bench = match &input.bench {
+                None => "null".to_string(),
+                Some(bench) => {
+                    let phases = bench
+                        .phases()
+                        .iter()
+                        .map(|(phase, stats)| format!("\"{phase}\":{}", json_stats(stats)))
+                        .collect::<Vec<String>>();
+                    format!(
+                        "{{\"warmup\":{},\"iterations\":{},{}}}",
+                        bench.warmup,
+                        bench.samples.len(),
+                        phases.join(",")
+                    )
+                }
+            };
+            let panic = match &input.solution {
+                Err(SolveError::Panic(panic)) => format!(
+                    "{{\"message\":{},\"location\":{}}}",
+                    json_string(&panic.message),
+                    panic
+                        .location
+                        .as_deref()
+                        .map_or("null".to_string(), json_string)
+                ),
+                _ => "null".to_string(),
+            };
+            let parse_error = match &input.solution {
+                Err(SolveError::Parse(error)) => format!(
+                    "{{\"line\":{},\"column\":{},\"expected\":{},\"found\":{}}}",
+                    error.line,
+                    error.column,
+                    json_string(&error.expected),
+                    json_string(&error.found)
+                ),
+                _ => "null".to_string(),
+            };
+            let allocations = match &input.solution {
+                Ok(Solution {
+                    allocations: Some(stats),
+                    ..
+                }) => format!(
+                    "{{\"count\":{},\"bytes\":{},\"peak_bytes\":{}}}",
+                    stats.allocations, stats.bytes, stats.peak_bytes
+                ),
+                _ => "null".to_string(),
+            };
+            let timeout = match &input.solution {
+                Err(SolveError::Timeout(limit)) => limit.as_nanos().to_string(),
+                _ => "null".to_string(),
+            };
+            inputs.push(format!(
+                "{{\"input\":{},\"timings_ns\":{timings},\"parts\":[{}],\"bench\":{bench},\"panic\":{panic},\"parse_error\":{parse_error},\"allocations\":{allocations},\"timeout_ns\":{timeout}}}",
+                json_string(&input.input.to_string_lossy()),
+                parts.join(","),
+            ));
+        }
+        days.push(format!(
+            "{{\"day\":{},\"name\":{},\"inputs\":[{}],\"empty_inputs\":[{}],\"summary\":{}}}",
+            report.day,
+            json_string(report.name),
+            inputs.join(","),
+            report
+                .empty_inputs
+                .iter()
+                .map(|name| json_string(name))
+                .collect::<Vec<String>>()
+                .join(","),
+            json_summary(&report.summary())
+        ));
+    }
+    format!(
+        "{{\"schema_version\":{JSON_SCHEMA_VERSION},\"days\":[{}],\"summary\":{}}}",
+        days.join(","),
+        json_summary(&total)
+    )
+}
+
+pub fn format_csv(reports: &[DayReport]) -> String {
+    let mut csv = String::from("day,name,input,part,answer,expected,verdict,parse_ns,part_ns,bench_min_ns,bench_median_ns,bench_mean_ns,bench_stddev_ns,bench_p95_ns,panic,parse_error,allocations,allocated_bytes,peak_bytes,timeout_ns\n");
+    for report in reports {
+        for input in &report.inputs {
+            let (answers, timings) = match &input.solution {
+                Ok(solution) => (
+                    [&solution.part1, &solution.part2].map(|answer| {
+                        answer
+                            .as_ref()
+                            .map_or(String::new(), |answer| answer.to_string())
+                    }),
+                    Some(solution.timings),
+                ),
+                Err(_) => ([String::new(), String::new()], None),
+            };
+            let allocations = match &input.solution {
+                Ok(Solution {
+                    allocations: Some(stats),
+                    ..
+                }) => [stats.allocations, stats.bytes, stats.peak_bytes].map(|x| x.to_string()),
+                _ => [String::new(), String::new(), String::new()],
+            };
+            let (panic, parse_error, timeout) = match &input.solution {
+                Ok(_) => (String::new(), String::new(), String::new()),
+                Err(SolveError::Panic(panic)) => (panic.to_string(), String::new(), String::new()),
+                Err(SolveError::Parse(error)) => (String::new(), error.to_string(), String::new()),
+                Err(SolveError::Timeout(limit)) => {
+                    (String::new(), String::new(), limit.as_nanos().to_string())
+                }
+            };
+            for (part, answer, expected, verdict) in [
+                (1, &answers[0], &input.expected.part1, input.verdicts[0]),
+                (2, &answers[1], &input.expected.part2, input.verdicts[1]),
+            ] {
+                let bench = match &input.bench {
+                    None => vec![String::new(); 5],
+                    Some(bench) => {
+                        let stats = if part == 1 {
+                            bench.part1()
+                        } else {
+                            bench.part2()
+                        };
+                        [stats.min, stats.median, stats.mean, stats.stddev, stats.p95]
+                            .map(|x| x.as_nanos().to_string())
+                            .to_vec()
+                    }
+                };
+                let (parse_ns, part_ns) = match timings {
+                    Some(timings) => {
+                        let duration = if part == 1 {
+                            timings.part1
+                        } else {
+                            timings.part2
+                        };
+                        (
+                            timings.parse.as_nanos().to_string(),
+                            duration.as_nanos().to_string(),
+                        )
+                    }
+                    None => (String::new(), String::new()),
+                };
+                let fields = [
+                    report.day.to_string(),
+                    csv_field(report.name),
+                    csv_field(&input.input.to_string_lossy()),
+                    part.to_string(),
+                    csv_field(answer),
+                    csv_field(expected.as_deref().unwrap_or("")),
+                    verdict_name(input, verdict).to_string(),
+                    parse_ns,
+                    part_ns,
+                ];
+                writeln!(
+                    csv,
+                    "{},{},{},{},{},{timeout}",
+                    fields.join(","),
+                    bench.join(","),
+                    csv_field(&panic),
+                    csv_field(&parse_error),
+                    allocations.join(",")
+                )
+                .unwrap();
+            }
+        }
+    }
+    csv
+}
+
+fn verdict_name(input: &InputReport, verdict: Verdict) -> &'static str {
+    match input.solution {
+        Ok(_) => {}
+        Err(SolveError::Parse(_)) => return "parse_error",
+        Err(SolveError::Panic(_)) => return "panicked",
+        Err(SolveError::Timeout(_)) => return "timeout",
+    }
+    match verdict {
+        Verdict::Pass => "pass",
+        Verdict::Fail => "fail",
+        Verdict::Unknown => "unknown",
+        Verdict::Skipped => "skipped",
+    }
+}
+
+fn json_summary(summary: &VerdictSummary) -> String {
+    format!(
+        "{{\"passed\":{},\"failed\":{},\"unknown\":{},\"panicked\":{},\"parse_errors\":{},\"timeouts\":{}}}",
+        summary.passed,
+        summary.failed,
+        summary.unknown,
+        summary.panicked,
+        summary.parse_errors,
+        summary.timeouts
+    )
+}
+
+fn json_stats(stats: &Stats) -> String {
+    let ns = |duration: Duration| duration.as_nanos();
+    format!(
+        "{{\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{},\"p95_ns\":{}}}",
+        ns(stats.min),
+        ns(stats.median),
+        ns(stats.mean),
+        ns(stats.stddev),
+        ns(stats.p95)
+    )
+}
+
+pub fn json_string(text: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+pub fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{display_path, ParseError};
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Extension of the sidecar file holding the puzzle parameters of an input,
+/// e.g. `input/demo.params` for `input/demo.txt`.
+pub const PARAMS_EXTENSION: &str = "params";
+
+/// Puzzle parameters that are given in the puzzle text instead of the input, like grid sizes or
+/// step counts. Solvers fall back to their defaults for missing keys.
+///
+/// The sidecar uses the same `key=value` format as the expected answers:
+/// ```text
+/// width=11
+/// height=7
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Params {
+    file: Option<PathBuf>,
+    contents: String,
+    /// Byte ranges of key and value in `contents`.
+    entries: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl Params {
+    pub fn sidecar_path(input_file: &Path) -> PathBuf {
+        input_file.with_extension(PARAMS_EXTENSION)
+    }
+
+    /// The text of the sidecar, empty without one.
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+
+    /// The parameters of `input_file`, empty if it has no sidecar.
+    pub fn for_input(input_file: &Path) -> Result<Params, ParseError> {
+        let path = Self::sidecar_path(input_file);
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Ok(Params::default());
+        };
+        let path = display_path(&path);
+        let mut params =
+            Self::parse(&contents).map_err(|error| error.with_source(&path, &contents))?;
+        params.file = Some(path);
+        Ok(params)
+    }
+
+    pub fn parse(contents: &str) -> Result<Params, ParseError> {
+        let offset = |text: &str| text.as_ptr() as usize - contents.as_ptr() as usize;
+        let range = |text: &str| offset(text)..offset(text) + text.len();
+        let mut entries = Vec::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::at(contents, line, "a key=value line"))?;
+            entries.push((range(key.trim()), range(value.trim())));
+        }
+        Ok(Params {
+            file: None,
+            contents: contents.to_string(),
+            entries,
+        })
+    }
+
+    /// The value of `key`, the last one if it is given more than once. An error pointing into the
+    /// sidecar if the value does not parse.
+    pub fn get<T: FromStr>(&self, key: &str, expected: &str) -> Result<Option<T>, ParseError> {
+        let Some((_, value)) = self
+            .entries
+            .iter()
+            .rev()
+            .find(|(k, _)| &self.contents[k.clone()] == key)
+        else {
+            return Ok(None);
+        };
+        let value = &self.contents[value.clone()];
+        value.parse::<T>().map(Some).map_err(|_| {
+            let error = ParseError::at(&self.contents, value, expected);
+            match &self.file {
+                Some(file) => error.with_source(file, &self.contents),
+                None => error,
+            }
+        })
+    }
+}
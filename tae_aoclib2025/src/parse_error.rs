@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Malformed puzzle input, located by 1-based line and column (in characters).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by the runner, parsers only see the input text.
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    /// The offending input line, filled in by the runner for [`ParseError::render`].
+    pub source_line: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+            source_line: None,
+        }
+    }
+
+    /// An error pointing at `found`, which must be a slice of `input` (e.g. obtained via
+    /// `lines()`, `split()` or `trim()`), so that its position can be derived from its address.
+    /// An empty slice points at the end of its line.
+    pub fn at(input: &str, found: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (found.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(
+            offset <= input.len(),
+            "ParseError::at needs a slice of the parsed input"
+        );
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let found = found.lines().next().unwrap_or("");
+        let found = if !found.is_empty() {
+            format!("`{found}`")
+        } else if offset == input.len() {
+            "end of input".to_string()
+        } else {
+            "end of line".to_string()
+        };
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            expected,
+            found,
+        )
+    }
+
+    /// Attaches the file name and the offending line of `input` for diagnostics.
+    pub fn with_source(mut self, file: &Path, input: &str) -> ParseError {
+        self.file = Some(file.to_path_buf());
+        self.source_line = input
+            .lines()
+            .nth(self.line.saturating_sub(1))
+            .map(|line| line.to_string());
+        self
+    }
+
+    /// A compiler style diagnostic with a caret under the offending text:
+    /// ```text
+    /// error: expected a number, found `x`
+    ///  --> day_01/input/demo.txt:3:5
+    ///   |
+    /// 3 | 12 x
+    ///   |    ^
+    /// ```
+    pub fn render(&self) -> String {
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("<input>:{}:{}", self.line, self.column),
+        };
+        let mut lines = vec![
+            format!("error: expected {}, found {}", self.expected, self.found),
+            format!(" --> {location}"),
+        ];
+        if let Some(source_line) = &self.source_line {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let width = match self.found.strip_prefix('`') {
+                Some(quoted) => quoted.trim_end_matches('`').chars().count().max(1),
+                None => 1,
+            };
+            lines.push(format!("{gutter} |"));
+            lines.push(format!("{} | {source_line}", self.line));
+            lines.push(format!(
+                "{gutter} | {}{}",
+                " ".repeat(self.column - 1),
+                "^".repeat(width)
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, reporting `expected` at its position if that fails.
+pub fn parse_token<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+/// Like [`str::strip_prefix`], but with an error pointing at `text` if the prefix is missing.
+pub fn expect_prefix<'a>(input: &str, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, text, format!("`{prefix}`")))
+}
+
+/// Like [`str::split_once`], but with an error pointing at `text` if the delimiter is missing.
+pub fn expect_split_once<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, text, format!("`{delimiter}`")))
+}
+
+/// The next item of a split, or an error pointing at the end of `text` if there is none.
+pub fn expect_next<'a, I: Iterator<Item = &'a str>>(
+    input: &str,
+    text: &'a str,
+    items: &mut I,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    items
+        .next()
+        .ok_or_else(|| ParseError::at(input, &text[text.len()..], expected))
+}
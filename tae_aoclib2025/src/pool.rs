@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Runs `task` on every item using up to `jobs` worker threads and hands the results to
+/// `on_result` in the order of `items`, as soon as all earlier results are available.
+pub fn run_ordered<T, R, F, C>(jobs: usize, items: &[T], task: F, mut on_result: C)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    C: FnMut(usize, R),
+{
+    if jobs <= 1 || items.len() <= 1 {
+        for (index, item) in items.iter().enumerate() {
+            on_result(index, task(item));
+        }
+        return;
+    }
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, task) = (&next, &task);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                if sender.send((index, task(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_to_report) {
+                on_result(next_to_report, result);
+                next_to_report += 1;
+            }
+        }
+    });
+}
@@ -0,0 +1,96 @@
+use crate::{format_duration, DynSolver, Params, ParseError, Parts, PhaseTimings};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// How long `--profile` keeps solving: `10000` iterations or `5s`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ProfileLimit {
+    Iterations(usize),
+    Time(Duration),
+}
+
+impl FromStr for ProfileLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_suffix('s') {
+            Some(seconds) => seconds
+                .parse::<f64>()
+                .ok()
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                .filter(|time| !time.is_zero())
+                .map(ProfileLimit::Time)
+                .ok_or("expected a positive number of seconds like 5s".to_string()),
+            None => s
+                .parse::<usize>()
+                .ok()
+                .filter(|iterations| *iterations > 0)
+                .map(ProfileLimit::Iterations)
+                .ok_or("expected a positive iteration count or seconds like 5s".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileResult {
+    pub iterations: usize,
+    pub elapsed: Duration,
+    /// Summed over all iterations.
+    pub timings: PhaseTimings,
+}
+
+impl ProfileResult {
+    pub fn average(&self) -> PhaseTimings {
+        let n = self.iterations as u32;
+        PhaseTimings {
+            parse: self.timings.parse / n,
+            part1: self.timings.part1 / n,
+            part2: self.timings.part2 / n,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let average = self.average();
+        format!(
+            "{} iterations in {}, {} per iteration (parse {}, part 1 {}, part 2 {})",
+            self.iterations,
+            format_duration(self.elapsed),
+            format_duration(average.total()),
+            format_duration(average.parse),
+            format_duration(average.part1),
+            format_duration(average.part2)
+        )
+    }
+}
+
+/// Solves `input` in a tight loop without any output, so that external profilers like perf
+/// collect enough samples even for inputs solved in microseconds. Panics are not caught.
+pub fn profile_input(
+    solver: &dyn DynSolver,
+    input: &str,
+    params: &Params,
+    parts: Parts,
+    limit: ProfileLimit,
+) -> Result<ProfileResult, ParseError> {
+    let start = Instant::now();
+    let mut result = ProfileResult {
+        iterations: 0,
+        elapsed: Duration::ZERO,
+        timings: PhaseTimings::default(),
+    };
+    loop {
+        let timings = solver.solve(input, params, parts)?.timings;
+        result.iterations += 1;
+        result.timings.parse += timings.parse;
+        result.timings.part1 += timings.part1;
+        result.timings.part2 += timings.part2;
+        let done = match limit {
+            ProfileLimit::Iterations(iterations) => result.iterations >= iterations,
+            ProfileLimit::Time(time) => start.elapsed() >= time,
+        };
+        if done {
+            result.elapsed = start.elapsed();
+            return Ok(result);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Markdown and HTML reports of a run, for publishing the state of all days.
+//!
+//! Both have one row per input with the answers, the verdicts, the phase timings (bench medians
+//! if benchmarked) and a bar showing the input's share of the total runtime of all inputs.
+
+use crate::{format_duration, DayReport, InputReport, SolveError, Verdict, VerdictSummary};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+pub const MARKDOWN_REPORT: &str = "report.md";
+pub const HTML_REPORT: &str = "report.html";
+
+const BAR_WIDTH: usize = 20;
+
+/// Writes [`MARKDOWN_REPORT`] and [`HTML_REPORT`] into `dir`, creating it if needed.
+pub fn write_reports(reports: &[DayReport], dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(MARKDOWN_REPORT), format_markdown(reports))?;
+    fs::write(dir.join(HTML_REPORT), format_html(reports))
+}
+
+/// The cells of one report row, shared by both formats.
+struct Row {
+    day: String,
+    input: String,
+    answers: [String; 2],
+    status: String,
+    /// `pass`, `fail` or empty, for coloring the status in HTML.
+    class: &'static str,
+    timings: [String; 4],
+    /// Share of the total runtime of all inputs, between 0 and 1.
+    share: f64,
+}
+
+fn rows(reports: &[DayReport]) -> (Vec<Row>, VerdictSummary, Duration) {
+    let total_runtime: Duration = reports
+        .iter()
+        .flat_map(|report| &report.inputs)
+        .filter_map(|input| input.representative_timings())
+        .map(|timings| timings.total())
+        .sum();
+    let mut summary = VerdictSummary::default();
+    let mut rows = Vec::new();
+    for report in reports {
+        summary += report.summary();
+        for input in &report.inputs {
+            let timings = input.representative_timings();
+            let share = match timings {
+                Some(timings) if !total_runtime.is_zero() => {
+                    timings.total().as_secs_f64() / total_runtime.as_secs_f64()
+                }
+                _ => 0.0,
+            };
+            rows.push(Row {
+                day: format!("{} ({})", report.label, report.name),
+                input: input.name.clone(),
+                answers: answers(input),
+                status: status(input),
+                class: status_class(input),
+                timings: match timings {
+                    Some(timings) => [timings.parse, timings.part1, timings.part2, timings.total()]
+                        .map(format_duration),
+                    None => ["-", "-", "-", "-"].map(String::from),
+                },
+                share,
+            });
+        }
+    }
+    (rows, summary, total_runtime)
+}
+
+fn answers(input: &InputReport) -> [String; 2] {
+    match &input.solution {
+        Ok(solution) => [&solution.part1, &solution.part2].map(|answer| {
+            answer
+                .as_ref()
+                .map_or("-".to_string(), |answer| answer.to_string())
+        }),
+        Err(_) => ["-", "-"].map(String::from),
+    }
+}
+
+fn status_class(input: &InputReport) -> &'static str {
+    let mut verdicts = input
+        .verdicts
+        .iter()
+        .filter(|verdict| **verdict != Verdict::Skipped);
+    if input.solution.is_err() || input.verdicts.contains(&Verdict::Fail) {
+        "fail"
+    } else if verdicts.all(|verdict| *verdict == Verdict::Pass) {
+        "pass"
+    } else {
+        ""
+    }
+}
+
+fn status(input: &InputReport) -> String {
+    match &input.solution {
+        Ok(_) => format!("{} {}", input.verdicts[0], input.verdicts[1]),
+        Err(SolveError::Parse(_)) => "PARSE ERROR".to_string(),
+        Err(SolveError::Panic(_)) => "PANICKED".to_string(),
+        Err(SolveError::Timeout(_)) => "TIMEOUT".to_string(),
+    }
+}
+
+pub fn format_markdown(reports: &[DayReport]) -> String {
+    let (rows, summary, total_runtime) = rows(reports);
+    let mut markdown = String::from("# Advent of Code results\n\n");
+    markdown.push_str(
+        "| day | input | part 1 | part 2 | status | parse | part 1 time | part 2 time | total | share |\n",
+    );
+    markdown.push_str("|---|---|---|---|---|---:|---:|---:|---:|---|\n");
+    for row in rows {
+        let filled = (row.share * BAR_WIDTH as f64).round() as usize;
+        let cells = [
+            row.day,
+            row.input,
+            // Multi-line answers (ASCII art) do not fit into a table cell
+            row.answers[0].replace('\n', "<br>"),
+            row.answers[1].replace('\n', "<br>"),
+            row.status,
+            row.timings[0].clone(),
+            row.timings[1].clone(),
+            row.timings[2].clone(),
+            row.timings[3].clone(),
+            format!(
+                "`{}{}` {:.1}%",
+                "█".repeat(filled),
+                "░".repeat(BAR_WIDTH - filled),
+                row.share * 100.0
+            ),
+        ]
+        .map(|cell| cell.replace('|', "\\|"));
+        writeln!(markdown, "| {} |", cells.join(" | ")).unwrap();
+    }
+    writeln!(
+        markdown,
+        "\n**Total:** {summary}, {}",
+        format_duration(total_runtime)
+    )
+    .unwrap();
+    markdown
+}
+
+pub fn format_html(reports: &[DayReport]) -> String {
+    let (rows, summary, total_runtime) = rows(reports);
+    let mut html = String::from(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Advent of Code results</title>
+<style>
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.6em; vertical-align: top; }
+td.time { text-align: right; font-family: monospace; }
+td.answer { font-family: monospace; white-space: pre; }
+td.pass { background: #dfd; }
+td.fail { background: #fdd; }
+.bar { width: 10em; height: 0.8em; background: #eee; display: inline-block; }
+.bar div { height: 100%; background: #48c; }
+</style>
+</head>
+<body>
+<h1>Advent of Code results</h1>
+<table>
+<tr><th>day</th><th>input</th><th>part 1</th><th>part 2</th><th>status</th><th>parse</th><th>part 1 time</th><th>part 2 time</th><th>total</th><th>share</th></tr>
+"#,
+    );
+    for row in rows {
+        write!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td class=\"answer\">{}</td><td class=\"answer\">{}</td><td class=\"{}\">{}</td>",
+            html_escape(&row.day),
+            html_escape(&row.input),
+            html_escape(&row.answers[0]),
+            html_escape(&row.answers[1]),
+            row.class,
+            html_escape(&row.status),
+        )
+        .unwrap();
+        for timing in &row.timings {
+            write!(html, "<td class=\"time\">{}</td>", html_escape(timing)).unwrap();
+        }
+        writeln!(
+            html,
+            "<td><div class=\"bar\"><div style=\"width: {:.1}%\"></div></div> {:.1}%</td></tr>",
+            row.share * 100.0,
+            row.share * 100.0
+        )
+        .unwrap();
+    }
+    writeln!(
+        html,
+        "</table>\n<p><b>Total:</b> {}, {}</p>\n</body>\n</html>",
+        html_escape(&summary.to_string()),
+        html_escape(&format_duration(total_runtime))
+    )
+    .unwrap();
+    html
+}
+
+pub fn html_escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,528 @@
+use crate::pool::run_ordered;
+use crate::workspace::display_path;
+use crate::{
+    bench_input, catch_panic, check_baseline, format_csv, format_duration, format_json, glob_match,
+    init_logging, log_info, normalize_input, print_day_table, profile_input, watch, workspace_root,
+    write_reports, BenchResult, DynSolver, Expected, OutputFormat, Panic, Params, ParseError,
+    Parts, PhaseTimings, ProfileLimit, RunOptions, Solution, Solver, Verdict, VerdictSummary,
+    EXPECTED_EXTENSION, OPTIONS_USAGE, PARAMS_EXTENSION,
+};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Entry point of the single-day binaries: runs all inputs of the day and exits with a
+/// non-zero code if any answer does not match its expected value, an input does not parse or
+/// a solver panicked or timed out.
+pub fn solve_all_inputs<S: Solver + 'static>(solver: S) {
+    let options = match RunOptions::from_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{OPTIONS_USAGE}");
+            std::process::exit(2);
+        }
+    };
+    // Solvers exceeding --timeout keep running on a detached thread, so they must live forever
+    let solver: &'static S = Box::leak(Box::new(solver));
+    let summary = run_solvers(&[solver], &options);
+    if !summary.success() {
+        std::process::exit(1);
+    }
+}
+
+/// Why an input has no solution.
+#[derive(Debug, Clone)]
+pub enum SolveError {
+    Parse(ParseError),
+    Panic(Panic),
+    /// The solver did not finish within the time limit.
+    Timeout(Duration),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "{error}"),
+            SolveError::Panic(panic) => write!(f, "{panic}"),
+            SolveError::Timeout(limit) => {
+                write!(f, "exceeded the time limit of {}", format_duration(*limit))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputReport {
+    /// Path relative to the workspace root if possible, `<stdin>` for standard input.
+    pub input: PathBuf,
+    /// Path of the input relative to the day's input directory, used as its baseline key.
+    pub name: String,
+    pub expected: Expected,
+    pub solution: Result<Solution, SolveError>,
+    /// Both `Unknown` if the input could not be solved.
+    pub verdicts: [Verdict; 2],
+    pub bench: Option<BenchResult>,
+}
+
+impl InputReport {
+    /// The bench medians if the input was benchmarked, otherwise the timings of the single run.
+    /// `None` if the input could not be solved.
+    pub fn representative_timings(&self) -> Option<PhaseTimings> {
+        let solution = self.solution.as_ref().ok()?;
+        Some(match &self.bench {
+            Some(bench) => PhaseTimings {
+                parse: bench.parse().median,
+                part1: bench.part1().median,
+                part2: bench.part2().median,
+            },
+            None => solution.timings,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u32,
+    pub label: String,
+    pub name: &'static str,
+    pub inputs: Vec<InputReport>,
+    /// Inputs skipped because they are empty, usually placeholders for private puzzle inputs.
+    pub empty_inputs: Vec<String>,
+}
+
+impl DayReport {
+    pub fn summary(&self) -> VerdictSummary {
+        let mut summary = VerdictSummary::default();
+        for input in &self.inputs {
+            match input.solution {
+                Ok(_) => {}
+                Err(SolveError::Parse(_)) => {
+                    summary.parse_errors += 1;
+                    continue;
+                }
+                Err(SolveError::Panic(_)) => {
+                    summary.panicked += 1;
+                    continue;
+                }
+                Err(SolveError::Timeout(_)) => {
+                    summary.timeouts += 1;
+                    continue;
+                }
+            }
+            for verdict in input.verdicts {
+                summary.add(verdict);
+            }
+        }
+        summary
+    }
+}
+
+/// Runs all given days and prints their results in the requested output format.
+/// With `--jobs` the inputs of all days share one thread pool; days are still printed in order.
+pub fn run_solvers(solvers: &[&'static dyn DynSolver], options: &RunOptions) -> VerdictSummary {
+    if options.watch {
+        watch(solvers, options);
+    }
+    start_logging(options);
+    let mut reports: Vec<DayReport> = solvers.iter().map(|solver| empty_report(*solver)).collect();
+    let mut remaining = Vec::new();
+    let mut tasks = Vec::new();
+    let stdin = options.stdin.then(read_stdin);
+    for (day_index, solver) in solvers.iter().enumerate() {
+        let day_tasks = match &stdin {
+            Some(contents) => vec![InputTask::stdin(*solver, day_index, contents)],
+            None => input_tasks(*solver, day_index, options, &mut reports[day_index]),
+        };
+        remaining.push(day_tasks.len());
+        tasks.extend(day_tasks);
+    }
+    for pattern in &options.inputs {
+        if !tasks.iter().any(|task| task.matches(pattern)) {
+            eprintln!("No input matches {pattern}");
+        }
+    }
+    if let Some(limit) = options.profile {
+        return profile_task(&tasks, options, limit);
+    }
+
+    let mut printed = 0;
+    let mut print_finished_days = |reports: &[DayReport], remaining: &[usize]| {
+        while printed < reports.len() && remaining[printed] == 0 {
+            if options.format == OutputFormat::Table {
+                print_day_table(&reports[printed]);
+            }
+            printed += 1;
+        }
+    };
+    print_finished_days(&reports, &remaining);
+    // Benchmarks competing for cores would skew each other's timings
+    let jobs = if options.bench.is_some() {
+        if options.jobs > 1 {
+            eprintln!("note: --bench solves one input at a time, ignoring --jobs");
+        }
+        1
+    } else {
+        options.jobs
+    };
+    run_ordered(
+        jobs,
+        &tasks,
+        |task| run_input(task, options),
+        |index, input| {
+            let day_index = tasks[index].day_index;
+            reports[day_index].inputs.push(input);
+            remaining[day_index] -= 1;
+            print_finished_days(&reports, &remaining);
+        },
+    );
+
+    let mut total = VerdictSummary::default();
+    for report in &reports {
+        total += report.summary();
+    }
+    match options.format {
+        OutputFormat::Table => {
+            if reports.len() > 1 {
+                println!("total: {total}");
+            }
+        }
+        OutputFormat::Json => println!("{}", format_json(&reports)),
+        OutputFormat::Csv => print!("{}", format_csv(&reports)),
+    }
+    if let Some(dir) = &options.report_dir {
+        if let Err(err) = write_reports(&reports, dir) {
+            eprintln!("Could not write the report to {}: {err}", dir.display());
+        }
+    }
+    for message in check_baseline(&reports, &options.baseline) {
+        // Keep stdout machine readable for json and csv
+        if options.format == OutputFormat::Table {
+            println!("{message}");
+        } else {
+            eprintln!("{message}");
+        }
+    }
+    total
+}
+
+fn start_logging(options: &RunOptions) {
+    if let Err(err) = init_logging(&options.log) {
+        eprintln!("Could not open log file: {err}");
+        std::process::exit(2);
+    }
+}
+
+/// `--profile`: solves the one selected input in a loop and prints the average timings.
+fn profile_task(tasks: &[InputTask], options: &RunOptions, limit: ProfileLimit) -> VerdictSummary {
+    let [task] = tasks else {
+        eprintln!(
+            "--profile needs exactly one input, but {} are selected (e.g. `aoc 7 demo.txt --profile 5s`)",
+            tasks.len()
+        );
+        std::process::exit(2);
+    };
+    let result = task.params().and_then(|params| {
+        profile_input(task.solver, &task.contents, &params, options.parts, limit)
+    });
+    let mut summary = VerdictSummary::default();
+    match result {
+        Ok(result) => println!("{}: {}", task.display_path.display(), result.summary()),
+        Err(error) => {
+            let error = match error.file {
+                Some(_) => error,
+                None => error.with_source(&task.display_path, &task.contents),
+            };
+            eprintln!("{}", error.render());
+            summary.parse_errors += 1;
+        }
+    }
+    summary
+}
+
+/// A single input file of a day, the unit of work of the thread pool.
+struct InputTask {
+    solver: &'static dyn DynSolver,
+    day_index: usize,
+    contents: String,
+    /// Why the file could not be read, its contents are empty then.
+    read_error: Option<ParseError>,
+    /// `None` for stdin.
+    path: Option<PathBuf>,
+    display_path: PathBuf,
+    name: String,
+}
+
+impl InputTask {
+    fn stdin(solver: &'static dyn DynSolver, day_index: usize, contents: &str) -> InputTask {
+        let display_path = PathBuf::from("<stdin>");
+        InputTask {
+            solver,
+            day_index,
+            contents: normalize(solver, contents, &display_path),
+            read_error: None,
+            path: None,
+            display_path,
+            name: "<stdin>".to_string(),
+        }
+    }
+
+    /// The puzzle parameters from the sidecar, or why the input cannot be solved.
+    fn params(&self) -> Result<Params, ParseError> {
+        match (&self.read_error, &self.path) {
+            (Some(error), _) => Err(error.clone()),
+            (None, Some(path)) => Params::for_input(path),
+            (None, None) => Ok(Params::default()),
+        }
+    }
+
+    /// Whether the input is selected by `pattern`, given either relative to the input
+    /// directory (`demo*`) or as the path printed by the runner (`day_07/input/demo.txt`).
+    fn matches(&self, pattern: &str) -> bool {
+        glob_match(pattern, &self.name) || glob_match(pattern, &self.display_path.to_string_lossy())
+    }
+}
+
+fn empty_report(solver: &dyn DynSolver) -> DayReport {
+    DayReport {
+        day: solver.day(),
+        label: solver.label(),
+        name: solver.name(),
+        inputs: Vec::new(),
+        empty_inputs: Vec::new(),
+    }
+}
+
+fn read_stdin() -> String {
+    let mut contents = String::new();
+    if let Err(err) = io::stdin().read_to_string(&mut contents) {
+        eprintln!("Could not read stdin: {err}");
+        std::process::exit(2);
+    }
+    contents
+}
+
+/// The selected non-empty inputs of a day; empty ones are recorded in the report instead.
+fn input_tasks(
+    solver: &'static dyn DynSolver,
+    day_index: usize,
+    options: &RunOptions,
+    report: &mut DayReport,
+) -> Vec<InputTask> {
+    let input_dir = input_dir(solver, options);
+    let mut tasks = Vec::new();
+    for (contents, path) in get_files_from_dir(&input_dir) {
+        let name = path
+            .strip_prefix(&input_dir)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .to_string();
+        let display_path = display_path(&path);
+        // Unreadable inputs are reported like inputs that do not parse
+        let (contents, read_error) = match contents {
+            Ok(contents) => (normalize(solver, &contents, &display_path), None),
+            Err(err) => {
+                let mut error = ParseError::new(1, 1, "a UTF-8 text file", err.to_string());
+                error.file = Some(display_path.clone());
+                (String::new(), Some(error))
+            }
+        };
+        let task = InputTask {
+            solver,
+            day_index,
+            contents,
+            read_error,
+            display_path,
+            path: Some(path),
+            name,
+        };
+        if !options.inputs.is_empty() && !options.inputs.iter().any(|p| task.matches(p)) {
+            continue;
+        }
+        if task.contents.is_empty() && task.read_error.is_none() {
+            report.empty_inputs.push(task.name);
+        } else {
+            tasks.push(task);
+        }
+    }
+    tasks
+}
+
+/// The input as the solver expects it, warning about changes beyond the final newline.
+fn normalize(solver: &dyn DynSolver, contents: &str, display_path: &Path) -> String {
+    let (contents, changes) = normalize_input(contents, solver.trim_policy());
+    if !changes.is_empty() {
+        eprintln!(
+            "warning: {}: {}",
+            display_path.display(),
+            changes.join(", ")
+        );
+    }
+    contents
+}
+
+/// `--input-dir` (or its `day_NN` subdirectory, if there is one), otherwise `day_NN/input` in
+/// the workspace, falling back to `input` in the working directory for days outside of it.
+pub(crate) fn input_dir(solver: &dyn DynSolver, options: &RunOptions) -> PathBuf {
+    if let Some(dir) = &options.input_dir {
+        let day_dir = dir.join(solver.label());
+        return if day_dir.is_dir() {
+            day_dir
+        } else {
+            dir.clone()
+        };
+    }
+    let day_input_dir = workspace_root().join(solver.label()).join("input");
+    if day_input_dir.exists() {
+        day_input_dir
+    } else {
+        PathBuf::from("input")
+    }
+}
+
+fn run_input(task: &InputTask, options: &RunOptions) -> InputReport {
+    let expected = match &task.path {
+        Some(path) => Expected::for_input(path),
+        None => Ok(Expected::default()),
+    };
+    log_info!(
+        "solving {} ({} bytes)",
+        task.display_path.display(),
+        task.contents.len()
+    );
+    let params = task.params();
+    // A malformed sidecar fails only its own input
+    let (expected, params) = match (expected, params) {
+        (Ok(expected), params) => (expected, params),
+        (Err(error), _) => (Expected::default(), Err(error)),
+    };
+    let solve = |params: &Params| match options.timeout {
+        Some(limit) => {
+            solve_with_timeout(task.solver, &task.contents, params, options.parts, limit)
+        }
+        None => solve_isolated(task.solver, &task.contents, params, options.parts),
+    };
+    let mut solution = match &params {
+        Ok(params) => solve(params),
+        Err(error) => Err(SolveError::Parse(error.clone())),
+    };
+    let mut bench = None;
+    if let (Ok(_), Ok(params), Some(config)) = (&solution, &params, &options.bench) {
+        // Failing on a later run fails the input like failing on the first
+        match bench_input(config, || solve(params)) {
+            Ok(result) => bench = Some(result),
+            Err(error) => solution = Err(error),
+        }
+    }
+    let solution = solution.map_err(|error| match error {
+        // Errors in the sidecars already carry their source
+        SolveError::Parse(error) if error.file.is_none() => {
+            SolveError::Parse(error.with_source(&task.display_path, &task.contents))
+        }
+        error => error,
+    });
+    let verdicts = match &solution {
+        Ok(solution) => [
+            Verdict::check(expected.part1.as_deref(), solution.part1.as_ref()),
+            Verdict::check(expected.part2.as_deref(), solution.part2.as_ref()),
+        ],
+        Err(_) => [Verdict::Unknown; 2],
+    };
+    InputReport {
+        input: task.display_path.clone(),
+        name: task.name.clone(),
+        expected,
+        solution,
+        verdicts,
+        bench,
+    }
+}
+
+fn solve_isolated(
+    solver: &dyn DynSolver,
+    input: &str,
+    params: &Params,
+    parts: Parts,
+) -> Result<Solution, SolveError> {
+    match catch_panic(|| solver.solve(input, params, parts)) {
+        Ok(Ok(solution)) => Ok(solution),
+        Ok(Err(error)) => Err(SolveError::Parse(error)),
+        Err(panic) => Err(SolveError::Panic(panic)),
+    }
+}
+
+/// Solves on a separate thread and stops waiting for it after `limit`. Threads cannot be
+/// stopped from the outside, so a solver exceeding the limit keeps running in the background
+/// until the process exits.
+pub fn solve_with_timeout(
+    solver: &'static dyn DynSolver,
+    input: &str,
+    params: &Params,
+    parts: Parts,
+    limit: Duration,
+) -> Result<Solution, SolveError> {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    let params = params.clone();
+    thread::spawn(move || {
+        // The receiver is gone if the limit was exceeded
+        let _ = sender.send(solve_isolated(solver, &input, &params, parts));
+    });
+    receiver
+        .recv_timeout(limit)
+        .unwrap_or(Err(SolveError::Timeout(limit)))
+}
+
+/// All input files below `input_dir`, including subdirectories and empty files, sorted by
+/// path so that runs are reproducible. Sidecar files, hidden files and editor artifacts are
+/// skipped. Files that cannot be read as text are returned with their error.
+pub fn get_files_from_dir(input_dir: &Path) -> Vec<(io::Result<String>, PathBuf)> {
+    let mut paths = Vec::new();
+    collect_files(input_dir, &mut paths);
+    paths.sort();
+    paths
+        .into_iter()
+        .filter(|x| {
+            x.extension()
+                .is_none_or(|ext| ext != EXPECTED_EXTENSION && ext != PARAMS_EXTENSION)
+        })
+        .map(|x| (fs::read_to_string(&x), x))
+        .collect()
+}
+
+/// Hidden files and backups, like vim's `.demo.txt.swp` or `demo.txt~`, which come and go
+/// while inputs are edited.
+pub(crate) fn is_editor_artifact(path: &Path) -> bool {
+    path.file_name()
+        .map(|name| name.to_string_lossy())
+        .is_some_and(|name| name.starts_with('.') || name.ends_with('~'))
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            eprintln!(
+                "warning: could not read {}: {err}",
+                display_path(dir).display()
+            );
+            return;
+        }
+    };
+    // Entries vanishing while the directory is read are skipped
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if is_editor_artifact(&path) {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, paths);
+        } else {
+            paths.push(path);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use crate::log::set_input_len;
+use crate::{
+    measure_allocations, AllocStats, Answer, Differential, Disagreement, Params, ParseError, Rng,
+    TrimPolicy,
+};
+use std::time::{Duration, Instant};
+
+/// One day of the puzzle, split into a parse phase and the two parts working on the parsed input.
+/// Solvers are shared between the worker threads of the runner, hence `Sync`.
+pub trait Solver: Sync {
+    type Parsed;
+
+    fn day(&self) -> u32;
+
+    /// The puzzle title, e.g. "Bridge Repair".
+    fn name(&self) -> &'static str;
+
+    /// Trailing whitespace the runner removes from inputs before they are parsed.
+    fn trim_policy(&self) -> TrimPolicy {
+        TrimPolicy::Keep
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError>;
+
+    /// Parses `input` with the puzzle parameters from its `.params` sidecar. Only days with
+    /// parameters outside the input implement this, and their `parse` uses the defaults.
+    fn parse_with_params(&self, input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        let _ = params;
+        self.parse(input)
+    }
+
+    fn part1(&self, parsed: &Self::Parsed) -> Answer;
+
+    /// Days with a single part keep the default.
+    fn part2(&self, parsed: &Self::Parsed) -> Answer {
+        let _ = parsed;
+        Answer::None
+    }
+
+    /// A valid input with random content, for stress tests and benchmarks with data that can be
+    /// shared, unlike real puzzle inputs. `size` scales the input; what it counts depends on the
+    /// day, e.g. lines, grid side length or digits.
+    fn generate(&self, size: usize, rng: &mut Rng) -> String;
+
+    /// Reference and fast implementations of the same question, compared on generated and
+    /// fixture inputs by the `differential` binary. Most days have none.
+    fn differentials(&self) -> Vec<Differential<Self::Parsed>> {
+        Vec::new()
+    }
+}
+
+/// Object safe view of a [`Solver`], so that days with different parsed types can share a registry.
+pub trait DynSolver: Sync {
+    fn day(&self) -> u32;
+
+    fn name(&self) -> &'static str;
+
+    fn trim_policy(&self) -> TrimPolicy;
+
+    /// Parses `input` and runs the selected parts on it.
+    fn solve(&self, input: &str, params: &Params, parts: Parts) -> Result<Solution, ParseError>;
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> String;
+
+    fn differential_names(&self) -> Vec<&'static str>;
+
+    /// Parses `input` and runs every differential on it. Returns the ones that disagree.
+    fn check_differentials(
+        &self,
+        input: &str,
+        params: &Params,
+    ) -> Result<Vec<Disagreement>, ParseError>;
+
+    /// The crate and directory name of the day, e.g. `day_07`.
+    fn label(&self) -> String {
+        format!("day_{:02}", self.day())
+    }
+}
+
+impl<S: Solver> DynSolver for S {
+    fn day(&self) -> u32 {
+        Solver::day(self)
+    }
+
+    fn name(&self) -> &'static str {
+        Solver::name(self)
+    }
+
+    fn trim_policy(&self) -> TrimPolicy {
+        Solver::trim_policy(self)
+    }
+
+    fn generate(&self, size: usize, rng: &mut Rng) -> String {
+        Solver::generate(self, size, rng)
+    }
+
+    fn differential_names(&self) -> Vec<&'static str> {
+        self.differentials()
+            .iter()
+            .map(|differential| differential.name)
+            .collect()
+    }
+
+    fn check_differentials(
+        &self,
+        input: &str,
+        params: &Params,
+    ) -> Result<Vec<Disagreement>, ParseError> {
+        let parsed = self.parse_with_params(input, params)?;
+        Ok(self
+            .differentials()
+            .iter()
+            .filter_map(|differential| differential.check(&parsed))
+            .collect())
+    }
+
+    fn solve(&self, input: &str, params: &Params, parts: Parts) -> Result<Solution, ParseError> {
+        set_input_len(input.len());
+        let (solution, allocations) = measure_allocations(|| {
+            let start = Instant::now();
+            let parsed = self.parse_with_params(input, params)?;
+            let parse_duration = start.elapsed();
+
+            let (part1, part1_duration) = time_part(parts.part1, || self.part1(&parsed));
+            let (part2, part2_duration) = time_part(parts.part2, || self.part2(&parsed));
+
+            Ok(Solution {
+                part1,
+                part2,
+                timings: PhaseTimings {
+                    parse: parse_duration,
+                    part1: part1_duration,
+                    part2: part2_duration,
+                },
+                allocations: None,
+            })
+        });
+        let mut solution = solution?;
+        solution.allocations = allocations;
+        Ok(solution)
+    }
+}
+
+/// A part answering [`Answer::None`] is treated like a part that was not run.
+fn time_part(run: bool, part: impl FnOnce() -> Answer) -> (Option<Answer>, Duration) {
+    if !run {
+        return (None, Duration::ZERO);
+    }
+    let start = Instant::now();
+    let answer = part();
+    let duration = start.elapsed();
+    match answer {
+        Answer::None => (None, duration),
+        answer => (Some(answer), duration),
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhaseTimings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl PhaseTimings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Which parts to run after parsing.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Parts {
+    pub part1: bool,
+    pub part2: bool,
+}
+
+impl Parts {
+    pub const BOTH: Parts = Parts {
+        part1: true,
+        part2: true,
+    };
+
+    pub fn only(part: u8) -> Parts {
+        Parts {
+            part1: part == 1,
+            part2: part == 2,
+        }
+    }
+}
+
+impl Default for Parts {
+    fn default() -> Self {
+        Parts::BOTH
+    }
+}
+
+/// The answers of both parts together with the time each phase took.
+/// Answers of parts that were not run are `None` and take no time.
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub timings: PhaseTimings,
+    /// Allocations of all phases together, `None` without the `alloc-stats` feature.
+    pub allocations: Option<AllocStats>,
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solvers: Vec<Box<dyn DynSolver>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solver + 'static>(&mut self, solver: S) {
+        assert!(
+            self.get(Solver::day(&solver)).is_none(),
+            "Day {} registered twice",
+            Solver::day(&solver)
+        );
+        self.solvers.push(Box::new(solver));
+        self.solvers.sort_by_key(|solver| solver.day());
+    }
+
+    pub fn get(&self, day: u32) -> Option<&dyn DynSolver> {
+        self.iter().find(|solver| solver.day() == day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynSolver> {
+        self.solvers.iter().map(|solver| solver.as_ref())
+    }
+
+    /// Resolves a selection like `7`, `1..18` (inclusive), `3,5,9..11` or `all`.
+    pub fn select(&self, selection: &str) -> Result<Vec<&dyn DynSolver>, String> {
+        if selection == "all" {
+            return Ok(self.iter().collect());
+        }
+        let mut selected = Vec::new();
+        for part in selection.split(',') {
+            let (first, last) = match part.split_once("..") {
+                Some((first, last)) => (parse_day(first)?, parse_day(last)?),
+                None => (parse_day(part)?, parse_day(part)?),
+            };
+            if first > last {
+                return Err(format!("Empty day range: {part}"));
+            }
+            for day in first..=last {
+                match self.get(day) {
+                    Some(solver) => selected.push(solver),
+                    None => return Err(format!("Day {day} is not registered")),
+                }
+            }
+        }
+        Ok(selected)
+    }
+}
+
+fn parse_day(text: &str) -> Result<u32, String> {
+    text.trim()
+        .parse::<u32>()
+        .map_err(|_| format!("Not a day number: {text}"))
+}
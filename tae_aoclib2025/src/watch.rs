@@ -0,0 +1,73 @@
+use crate::runner::{input_dir, is_editor_artifact};
+use crate::{display_path, format_duration, run_solvers, DynSolver, RunOptions};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Size and modification time of every file, including sidecars but not editor artifacts.
+type Snapshot = BTreeMap<PathBuf, (u64, Option<SystemTime>)>;
+
+/// Runs the days, then re-runs them on a cleared screen whenever a file in one of their input
+/// directories is added, removed or changed. Polls instead of relying on a file notifier and
+/// only ends with Ctrl-C.
+pub fn watch(solvers: &[&'static dyn DynSolver], options: &RunOptions) -> ! {
+    let options = RunOptions {
+        watch: false,
+        ..options.clone()
+    };
+    let dirs: Vec<PathBuf> = solvers
+        .iter()
+        .map(|solver| input_dir(*solver, &options))
+        .collect();
+    loop {
+        let snapshot = snapshot(&dirs);
+        // Clear the screen and move the cursor to the top left
+        print!("\x1b[2J\x1b[H");
+        let start = Instant::now();
+        run_solvers(solvers, &options);
+        println!(
+            "\nRan in {}, watching {} for changes (Ctrl-C to stop)",
+            format_duration(start.elapsed()),
+            dirs.iter()
+                .map(|dir| display_path(dir).display().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        while self::snapshot(&dirs) == snapshot {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+fn snapshot(dirs: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for dir in dirs {
+        add_files(dir, &mut snapshot);
+    }
+    snapshot
+}
+
+/// Unlike the runner, tolerates directories vanishing while they are being edited.
+fn add_files(dir: &Path, snapshot: &mut Snapshot) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if is_editor_artifact(&path) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            add_files(&path, snapshot);
+        } else {
+            snapshot.insert(path, (metadata.len(), metadata.modified().ok()));
+        }
+    }
+}
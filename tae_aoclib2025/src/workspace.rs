@@ -0,0 +1,32 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The root of the AoC workspace: the workspace this library was built in, if it still exists.
+/// Otherwise, e.g. for a binary copied elsewhere, the closest ancestor of the working directory
+/// with a `[workspace]` manifest.
+pub fn workspace_root() -> PathBuf {
+    let build_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    if build_root.join("Cargo.toml").is_file() {
+        return build_root.to_path_buf();
+    }
+    if let Ok(cwd) = env::current_dir() {
+        for dir in cwd.ancestors() {
+            let manifest = fs::read_to_string(dir.join("Cargo.toml"));
+            if manifest.is_ok_and(|manifest| manifest.contains("[workspace]")) {
+                return dir.to_path_buf();
+            }
+        }
+    }
+    build_root.to_path_buf()
+}
+
+/// `path` relative to the workspace root if it is inside of it, so that output does not
+/// depend on the directory the runner was started from.
+pub fn display_path(path: &Path) -> PathBuf {
+    let root = workspace_root();
+    match path.strip_prefix(&root) {
+        Ok(relative) => relative.to_path_buf(),
+        Err(_) => path.to_path_buf(),
+    }
+}